@@ -1,19 +1,170 @@
+//! Native audio decoding, resampling, feature extraction, and effects, with a `PyAudioBuffer`
+//! Python binding over all of it, bound into `_hfendpoints` (the workspace's `#[pymodule]` entry
+//! point in `hfendpoints/src/lib.rs`) as the `audio` submodule.
+
 mod io {
-    use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+    use std::io::{Error as IoError, ErrorKind as IoErrorKind, Read};
+    use std::sync::mpsc::Receiver;
+    use std::sync::Mutex;
     use symphonia::core::audio::conv::FromSample;
     use symphonia::core::audio::sample::{i24, u24, Sample};
     use symphonia::core::audio::{Audio, AudioBuffer};
     use symphonia::core::codecs::audio::AudioDecoderOptions;
     use symphonia::core::codecs::CodecParameters;
+    use symphonia::core::errors::Error as SymphoniaError;
     use symphonia::core::errors::Result as SymphoniaResult;
     use symphonia::core::formats::probe::Hint;
     use symphonia::core::formats::{FormatOptions, TrackType};
-    use symphonia::core::io::{BufReader, MediaSourceStream, MediaSourceStreamOptions};
+    use symphonia::core::io::{
+        BufReader, MediaSource, MediaSourceStream, MediaSourceStreamOptions,
+    };
     use symphonia::core::meta::MetadataOptions;
     use symphonia::core::units::Time;
     use symphonia::default::{get_codecs, get_probe};
     use tracing::instrument;
 
+    /// A `symphonia` [`MediaSource`] backed by a channel of byte chunks, so probing/decoding can
+    /// begin before the whole upload has arrived (e.g. while an `axum` `Multipart` field is still
+    /// being read chunk by chunk). Not seekable -- each chunk is consumed once and never buffered
+    /// beyond what the decoder hasn't yet read.
+    ///
+    /// Wrapped in a `Mutex` purely so the type is `Sync` regardless of whether `Receiver` is;
+    /// `Read::read` takes `&mut self`, so there is never any real contention on the lock.
+    struct ChannelMediaSource {
+        receiver: Mutex<Receiver<Vec<u8>>>,
+        pending: Vec<u8>,
+        pending_offset: usize,
+        finished: bool,
+    }
+
+    impl ChannelMediaSource {
+        fn new(receiver: Receiver<Vec<u8>>) -> Self {
+            Self {
+                receiver: Mutex::new(receiver),
+                pending: Vec::new(),
+                pending_offset: 0,
+                finished: false,
+            }
+        }
+    }
+
+    impl Read for ChannelMediaSource {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.pending_offset >= self.pending.len() {
+                if self.finished {
+                    return Ok(0);
+                }
+
+                match self.receiver.get_mut().unwrap().recv() {
+                    Ok(chunk) => {
+                        self.pending = chunk;
+                        self.pending_offset = 0;
+                    }
+                    Err(_) => {
+                        self.finished = true;
+                        return Ok(0);
+                    }
+                }
+            }
+
+            let available = &self.pending[self.pending_offset..];
+            let n = available.len().min(buf.len());
+            buf[..n].copy_from_slice(&available[..n]);
+            self.pending_offset += n;
+            Ok(n)
+        }
+    }
+
+    impl MediaSource for ChannelMediaSource {
+        fn is_seekable(&self) -> bool {
+            false
+        }
+
+        fn byte_len(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    /// Streaming counterpart to [`load_audio`]: instead of requiring the whole upload to be
+    /// buffered up front, decodes packets as byte chunks arrive over `chunks` (fed by the caller,
+    /// e.g. from a bounded queue filled while reading an `axum` `Multipart` field), so
+    /// format-probe failures surface as soon as the container can be identified rather than after
+    /// the full transfer, and peak memory stays bounded to one decode buffer regardless of
+    /// upload size.
+    ///
+    /// Reachable from Python via the `audio` submodule bound in `hfendpoints/src/lib.rs`.
+    #[instrument(skip_all)]
+    pub fn load_audio_streaming(
+        chunks: Receiver<Vec<u8>>,
+    ) -> SymphoniaResult<(Vec<f32>, Time, CodecParameters)> {
+        let codecs = get_codecs();
+        let probe = get_probe();
+
+        let source: Box<dyn MediaSource> = Box::new(ChannelMediaSource::new(chunks));
+        let stream = MediaSourceStream::new(source, MediaSourceStreamOptions::default());
+
+        // Detect audio format
+        let hint = Hint::default();
+        let mut guess = probe.probe(
+            &hint,
+            stream,
+            FormatOptions::default(),
+            MetadataOptions::default(),
+        )?;
+
+        // Allocate audio decoder for the target audio format
+        let track = guess.default_track(TrackType::Audio).ok_or(IoError::new(
+            IoErrorKind::InvalidData,
+            "Failed to decode audio as no track was discovered while skimming through the provided data.",
+        ))?;
+
+        let codec_params = track.codec_params.as_ref().unwrap().clone();
+        let mut decoder = codecs.make_audio_decoder(
+            &track.codec_params.as_ref().unwrap().audio().unwrap(),
+            &AudioDecoderOptions::default(),
+        )?;
+
+        let mut out = Vec::new();
+        loop {
+            match guess.format.next_packet() {
+                Ok(packet) => {
+                    let decoded = decoder.decode(&packet)?;
+                    let mut converted =
+                        AudioBuffer::<f32>::new(decoded.spec().clone(), decoded.capacity());
+                    decoded.copy_to(&mut converted);
+                    converted.copy_to_vec_interleaved(&mut out);
+                }
+                Err(SymphoniaError::IoError(e)) if e.kind() == IoErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let channels = codec_params
+            .audio()
+            .and_then(|audio| audio.channels.as_ref().map(|channels| channels.count()))
+            .unwrap_or(1)
+            .max(1);
+        let sample_rate = codec_params
+            .audio()
+            .and_then(|audio| audio.sample_rate)
+            .unwrap_or(0);
+
+        let duration = if sample_rate > 0 {
+            let frames = out.len() / channels;
+            Time {
+                seconds: frames as u64 / sample_rate as u64,
+                frac: (frames % sample_rate as usize) as f64 / sample_rate as f64,
+            }
+        } else {
+            Time {
+                seconds: 0,
+                frac: 0.0,
+            }
+        };
+
+        Ok((out, duration, codec_params))
+    }
+
     #[instrument(skip_all)]
     pub fn load_audio<T>(wave: &[u8]) -> SymphoniaResult<(Vec<f32>, Time, CodecParameters)>
     where
@@ -78,12 +229,260 @@ mod io {
         Ok((raw_audio_buffer, duration, codec_params.clone()))
     }
 
+    /// Periodic Hann window of length `n`, as used by STFT-based feature extractors (periodic,
+    /// rather than symmetric, so consecutive overlapping frames tile without a seam).
+    fn hann_window_periodic(n: usize) -> Vec<f32> {
+        if n <= 1 {
+            return vec![1.0; n];
+        }
+
+        (0..n)
+            .map(|i| {
+                let phase = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                (0.5 - 0.5 * phase.cos()) as f32
+            })
+            .collect()
+    }
+
+    /// Power spectrum `|X|^2` of `frame` over its `frame.len() / 2 + 1` non-redundant real-FFT
+    /// bins, computed via the direct DFT definition.
+    fn power_spectrum(frame: &[f32]) -> Vec<f32> {
+        let n = frame.len();
+        let n_bins = n / 2 + 1;
+
+        (0..n_bins)
+            .map(|k| {
+                let mut re = 0.0f64;
+                let mut im = 0.0f64;
+                for (t, &sample) in frame.iter().enumerate() {
+                    let angle = -2.0 * std::f64::consts::PI * k as f64 * t as f64 / n as f64;
+                    re += sample as f64 * angle.cos();
+                    im += sample as f64 * angle.sin();
+                }
+                (re * re + im * im) as f32
+            })
+            .collect()
+    }
+
+    fn hz_to_mel(hz: f64) -> f64 {
+        2595.0 * (1.0 + hz / 700.0).log10()
+    }
+
+    fn mel_to_hz(mel: f64) -> f64 {
+        700.0 * (10f64.powf(mel / 2595.0) - 1.0)
+    }
+
+    /// Builds `n_mels` triangular filters over the `n_fft / 2 + 1` power-spectrum bins, equally
+    /// spaced in mel space between 0 Hz and Nyquist.
+    fn mel_filterbank(n_fft: usize, n_mels: usize, sample_rate: u32) -> Vec<Vec<f32>> {
+        let n_bins = n_fft / 2 + 1;
+        let nyquist = sample_rate as f64 / 2.0;
+        let mel_min = hz_to_mel(0.0);
+        let mel_max = hz_to_mel(nyquist);
+
+        let bin_points: Vec<f64> = (0..n_mels + 2)
+            .map(|i| {
+                let mel = mel_min + (mel_max - mel_min) * i as f64 / (n_mels + 1) as f64;
+                mel_to_hz(mel) * n_fft as f64 / sample_rate as f64
+            })
+            .collect();
+
+        (0..n_mels)
+            .map(|m| {
+                let left = bin_points[m];
+                let center = bin_points[m + 1];
+                let right = bin_points[m + 2];
+
+                (0..n_bins)
+                    .map(|bin| {
+                        let bin = bin as f64;
+                        if bin < left || bin > right {
+                            0.0
+                        } else if bin <= center {
+                            ((bin - left) / (center - left).max(1e-10)) as f32
+                        } else {
+                            ((right - bin) / (right - center).max(1e-10)) as f32
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Computes the log-mel spectrogram of `pcm`, framed into overlapping `n_fft`-sample windows
+    /// advanced by `hop_length` and mapped onto `n_mels` triangular mel filters, as consumed by
+    /// Whisper-style encoders and Conformers. Returns a `[n_mels, n_frames]` matrix.
+    ///
+    /// Reachable from Python via the `audio` submodule bound in `hfendpoints/src/lib.rs`.
+    #[instrument(skip(pcm))]
+    pub fn log_mel_spectrogram(
+        pcm: &[f32],
+        sample_rate: u32,
+        n_fft: usize,
+        hop_length: usize,
+        n_mels: usize,
+    ) -> Vec<Vec<f32>> {
+        let window = hann_window_periodic(n_fft);
+        let filterbank = mel_filterbank(n_fft, n_mels, sample_rate);
+        let n_frames = if pcm.len() >= n_fft {
+            (pcm.len() - n_fft) / hop_length.max(1) + 1
+        } else {
+            0
+        };
+
+        let mut mel = vec![vec![0.0f32; n_frames]; n_mels];
+        for frame_index in 0..n_frames {
+            let start = frame_index * hop_length;
+            let windowed: Vec<f32> = (0..n_fft).map(|i| pcm[start + i] * window[i]).collect();
+            let power = power_spectrum(&windowed);
+
+            for (m, filter) in filterbank.iter().enumerate() {
+                let energy: f32 = filter.iter().zip(power.iter()).map(|(f, p)| f * p).sum();
+                mel[m][frame_index] = energy.max(1e-10).log10();
+            }
+        }
+
+        mel
+    }
+
+    /// A single preprocessing step in a SoX-style effects chain, applied in place to a decoded
+    /// PCM buffer ahead of inference.
+    ///
+    /// Reachable from Python via the `audio` submodule bound in `hfendpoints/src/lib.rs`.
+    #[derive(Clone, Debug)]
+    pub enum Effect {
+        /// Multiplies every sample by `10^(db/20)`.
+        Gain(f32),
+
+        /// Peak-normalizes the buffer so `max|x| == 1.0`.
+        Normalize,
+
+        /// Drops leading/trailing `window_ms` windows whose RMS falls below `threshold_db`
+        /// relative to the buffer's peak.
+        TrimSilence { threshold_db: f32, window_ms: f32 },
+
+        /// Averages all channels down to a single mono channel.
+        DownmixMono,
+    }
+
+    fn apply_gain(pcm: &mut [f32], db: f32) {
+        let factor = 10f32.powf(db / 20.0);
+        for sample in pcm.iter_mut() {
+            *sample *= factor;
+        }
+    }
+
+    fn apply_normalize(pcm: &mut [f32]) {
+        let peak = pcm.iter().fold(0.0f32, |acc, &sample| acc.max(sample.abs()));
+        if peak > 0.0 {
+            apply_gain(pcm, 20.0 * (1.0 / peak).log10());
+        }
+    }
+
+    fn apply_downmix_mono(pcm: &[f32], channels: usize) -> Vec<f32> {
+        if channels <= 1 {
+            return pcm.to_vec();
+        }
+
+        pcm.chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect()
+    }
+
+    fn apply_trim_silence(
+        pcm: &[f32],
+        channels: usize,
+        sample_rate: u32,
+        threshold_db: f32,
+        window_ms: f32,
+    ) -> Vec<f32> {
+        let frames = pcm.len() / channels;
+        if frames == 0 {
+            return Vec::new();
+        }
+
+        let peak = pcm.iter().fold(0.0f32, |acc, &sample| acc.max(sample.abs()));
+        if peak <= 0.0 {
+            return pcm.to_vec();
+        }
+
+        let window_frames = ((window_ms / 1000.0) * sample_rate as f32).round().max(1.0) as usize;
+        let threshold = peak * 10f32.powf(threshold_db / 20.0);
+
+        let window_rms = |start: usize| -> f32 {
+            let end = (start + window_frames).min(frames);
+            if end <= start {
+                return 0.0;
+            }
+
+            let mut sum_sq = 0.0f64;
+            let mut count = 0usize;
+            for frame in start..end {
+                for channel in 0..channels {
+                    let sample = pcm[frame * channels + channel] as f64;
+                    sum_sq += sample * sample;
+                    count += 1;
+                }
+            }
+
+            (sum_sq / count.max(1) as f64).sqrt() as f32
+        };
+
+        let mut first = 0usize;
+        while first < frames && window_rms(first) < threshold {
+            first += window_frames;
+        }
+
+        let mut last = frames;
+        while last > first {
+            let start = last.saturating_sub(window_frames);
+            if window_rms(start) < threshold {
+                last = start;
+            } else {
+                break;
+            }
+        }
+
+        pcm[first * channels..last * channels].to_vec()
+    }
+
+    /// Applies `effects` to `pcm` (interleaved, `channels`-channel, `sample_rate` Hz) in order,
+    /// returning the transformed buffer and its (possibly updated) channel count.
+    pub fn apply_effects(
+        pcm: &[f32],
+        channels: usize,
+        sample_rate: u32,
+        effects: &[Effect],
+    ) -> (Vec<f32>, usize) {
+        let mut pcm = pcm.to_vec();
+        let mut channels = channels.max(1);
+
+        for effect in effects {
+            match effect {
+                Effect::Gain(db) => apply_gain(&mut pcm, *db),
+                Effect::Normalize => apply_normalize(&mut pcm),
+                Effect::TrimSilence {
+                    threshold_db,
+                    window_ms,
+                } => pcm = apply_trim_silence(&pcm, channels, sample_rate, *threshold_db, *window_ms),
+                Effect::DownmixMono => {
+                    pcm = apply_downmix_mono(&pcm, channels);
+                    channels = 1;
+                }
+            }
+        }
+
+        (pcm, channels)
+    }
+
     #[cfg(feature = "python")]
     pub(crate) mod python {
-        use crate::io::load_audio;
+        use crate::io::{apply_effects, load_audio, log_mel_spectrogram, Effect};
         use hfendpoints_binding_python::ImportablePyModuleBuilder;
-        use pyo3::exceptions::PyIOError;
+        use numpy::PyArray2;
+        use pyo3::exceptions::{PyIOError, PyValueError};
         use pyo3::prelude::*;
+        use symphonia::core::audio::Channels;
         use symphonia::core::codecs::CodecParameters;
         use symphonia::core::units::Time;
 
@@ -123,7 +522,171 @@ mod io {
                     .unwrap_or(0)
             }
 
-            fn resample(&mut self) {}
+            /// Resamples `pcm` to `target_sample_rate` using windowed-sinc (polyphase)
+            /// interpolation, so callers can normalize arbitrary-rate uploads (e.g. 44.1/48 kHz)
+            /// to whatever rate the downstream model expects (typically 16 kHz for ASR).
+            fn resample(&mut self, target_sample_rate: u32) {
+                let source_sample_rate = self.sample_rate();
+                if source_sample_rate == 0
+                    || target_sample_rate == 0
+                    || source_sample_rate == target_sample_rate
+                {
+                    return;
+                }
+
+                let channels = self.channels().max(1);
+                self.pcm = resample_pcm(
+                    &self.pcm,
+                    channels,
+                    source_sample_rate,
+                    target_sample_rate,
+                );
+
+                if let Some(audio) = self.codec.audio_mut() {
+                    audio.sample_rate = Some(target_sample_rate);
+                }
+            }
+
+            /// Computes the `[n_mels, n_frames]` log-mel spectrogram of the decoded PCM, as
+            /// consumed by Whisper-style encoders and Conformers.
+            #[pyo3(signature = (n_fft=400, hop_length=160, n_mels=80))]
+            fn log_mel_spectrogram<'py>(
+                &self,
+                py: Python<'py>,
+                n_fft: usize,
+                hop_length: usize,
+                n_mels: usize,
+            ) -> PyResult<Bound<'py, PyArray2<f32>>> {
+                let mel =
+                    log_mel_spectrogram(&self.pcm, self.sample_rate(), n_fft, hop_length, n_mels);
+                PyArray2::from_vec2(py, &mel).map_err(|e| PyValueError::new_err(e.to_string()))
+            }
+
+            /// Multiplies every sample by `10^(db/20)`.
+            fn gain(&mut self, db: f32) {
+                self.run_effects(&[Effect::Gain(db)]);
+            }
+
+            /// Peak-normalizes the buffer so `max|x| == 1.0`.
+            fn normalize(&mut self) {
+                self.run_effects(&[Effect::Normalize]);
+            }
+
+            /// Drops leading/trailing `window_ms` windows whose RMS falls below `threshold_db`
+            /// relative to the buffer's peak.
+            #[pyo3(signature = (threshold_db=-40.0, window_ms=20.0))]
+            fn trim_silence(&mut self, threshold_db: f32, window_ms: f32) {
+                self.run_effects(&[Effect::TrimSilence {
+                    threshold_db,
+                    window_ms,
+                }]);
+            }
+
+            /// Averages all channels down to a single mono channel.
+            fn downmix_mono(&mut self) {
+                self.run_effects(&[Effect::DownmixMono]);
+            }
+        }
+
+        impl PyAudioBuffer {
+            /// Runs `effects` in order against `pcm`, updating `duration` and the channel count
+            /// tracked in `codec` to match the transformed buffer.
+            fn run_effects(&mut self, effects: &[Effect]) {
+                let sample_rate = self.sample_rate();
+                let channels_before = self.channels().max(1);
+
+                let (pcm, channels_after) =
+                    apply_effects(&self.pcm, channels_before, sample_rate, effects);
+
+                if channels_after != channels_before {
+                    if let Some(audio) = self.codec.audio_mut() {
+                        audio.channels = Some(Channels::FRONT_LEFT);
+                    }
+                }
+
+                let frames = pcm.len() / channels_after.max(1);
+                self.duration = time_from_frames(frames, sample_rate);
+                self.pcm = pcm;
+            }
+        }
+
+        /// Builds a [`Time`] from a frame count and sample rate, as used to keep `duration` in
+        /// sync after an effect changes the number of frames (e.g. [`Effect::TrimSilence`]).
+        fn time_from_frames(frames: usize, sample_rate: u32) -> Time {
+            if sample_rate == 0 {
+                return Time {
+                    seconds: 0,
+                    frac: 0.0,
+                };
+            }
+
+            let seconds = frames as u64 / sample_rate as u64;
+            let frac = (frames % sample_rate as usize) as f64 / sample_rate as f64;
+
+            Time { seconds, frac }
+        }
+
+        /// Half-width (in input samples) of the windowed-sinc kernel used by [`resample_pcm`].
+        const RESAMPLE_KERNEL_HALF_WIDTH: isize = 16;
+
+        /// `sinc(t) * hann(t)`, the windowed-sinc interpolation kernel, zero outside
+        /// `|t| > half_width`.
+        fn windowed_sinc(t: f64, half_width: f64) -> f64 {
+            if t.abs() > half_width {
+                return 0.0;
+            }
+
+            let sinc = if t.abs() < 1e-9 {
+                1.0
+            } else {
+                (std::f64::consts::PI * t).sin() / (std::f64::consts::PI * t)
+            };
+            let window = 0.5 * (1.0 + (std::f64::consts::PI * t / half_width).cos());
+
+            sinc * window
+        }
+
+        /// Resamples interleaved, multi-channel `pcm` from `source_sample_rate` to
+        /// `target_sample_rate` via windowed-sinc (polyphase) interpolation, clamping input
+        /// indices at the signal boundaries (zero-pad) and scaling gain by `min(1, ratio)` to
+        /// avoid aliasing on downsampling.
+        fn resample_pcm(
+            pcm: &[f32],
+            channels: usize,
+            source_sample_rate: u32,
+            target_sample_rate: u32,
+        ) -> Vec<f32> {
+            let ratio = target_sample_rate as f64 / source_sample_rate as f64;
+            let gain = ratio.min(1.0);
+            let half_width = RESAMPLE_KERNEL_HALF_WIDTH;
+            let frames = pcm.len() / channels;
+            let out_frames = ((frames as f64) * ratio).round() as usize;
+
+            let sample_at = |frame: isize, channel: usize| -> f32 {
+                if frame < 0 || frame as usize >= frames {
+                    0.0
+                } else {
+                    pcm[frame as usize * channels + channel]
+                }
+            };
+
+            let mut resampled = vec![0.0f32; out_frames * channels];
+            for n in 0..out_frames {
+                let source_pos = n as f64 / ratio;
+                let base = source_pos.floor() as isize;
+                let frac = source_pos - base as f64;
+
+                for channel in 0..channels {
+                    let mut acc = 0.0f64;
+                    for k in -half_width..=half_width {
+                        let weight = windowed_sinc(k as f64 - frac, half_width as f64);
+                        acc += sample_at(base + k, channel) as f64 * weight;
+                    }
+                    resampled[n * channels + channel] = (acc * gain) as f32;
+                }
+            }
+
+            resampled
         }
 
         #[pyfunction(name = "load_audio_to_pcm")]
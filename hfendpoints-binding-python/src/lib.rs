@@ -6,6 +6,8 @@ use pyo3::types::{PyList, PyNone};
 use pyo3::{ffi, PyClass};
 use std::ffi::CString;
 
+pub mod logging;
+
 pub struct ImportablePyModuleBuilder<'py> {
     inner: Bound<'py, PyModule>,
 }
@@ -0,0 +1,98 @@
+//! Bridges Rust `tracing` events into Python's standard `logging` module, so endpoint authors
+//! can see what `wait_for_requests` (and everything else instrumented with `tracing`) is doing
+//! through their existing Python logging configuration instead of needing a separate Rust-side
+//! log sink.
+
+use crate::ImportablePyModuleBuilder;
+use pyo3::types::PyDict;
+use pyo3::{PyResult, Python};
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// A `tracing_subscriber::Layer` that forwards every event to `logging.getLogger(target)`,
+/// mapping `tracing::Level` onto the matching `logging` level and attaching every field (other
+/// than the formatted message) as `extra`.
+struct PythonLoggingLayer;
+
+impl<S: Subscriber> Layer<S> for PythonLoggingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        Python::with_gil(|py| {
+            if let Err(err) = forward_event(py, event) {
+                err.print(py);
+            }
+        });
+    }
+}
+
+/// Collects an event's fields into a Python `dict`, pulling the conventional `message` field
+/// out separately since it becomes the log record's message rather than one of its `extra`
+/// entries.
+struct FieldsVisitor<'py> {
+    message: Option<String>,
+    extra: pyo3::Bound<'py, PyDict>,
+}
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}").trim_matches('"').to_string());
+        } else {
+            let _ = self.extra.set_item(field.name(), format!("{value:?}"));
+        }
+    }
+}
+
+/// Maps a `tracing::Level` onto the numeric value of the matching `logging` level
+/// (`logging.DEBUG`, `logging.INFO`, ...), so we don't have to import the `logging` module just
+/// to read its constants back out.
+fn python_level(level: &Level) -> i32 {
+    match *level {
+        Level::ERROR => 40,
+        Level::WARN => 30,
+        Level::INFO => 20,
+        Level::DEBUG => 10,
+        Level::TRACE => 5,
+    }
+}
+
+fn forward_event(py: Python<'_>, event: &Event<'_>) -> PyResult<()> {
+    let logging = py.import("logging")?;
+    let logger = logging.call_method1("getLogger", (event.metadata().target(),))?;
+
+    let extra = PyDict::new(py);
+    let mut visitor = FieldsVisitor {
+        message: None,
+        extra: extra.clone(),
+    };
+    event.record(&mut visitor);
+
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("extra", extra)?;
+    logger.call_method(
+        "log",
+        (python_level(event.metadata().level()), visitor.message.unwrap_or_default()),
+        Some(&kwargs),
+    )?;
+
+    Ok(())
+}
+
+/// Installs the `tracing` -> `logging` bridge as the process' global subscriber and returns the
+/// (otherwise empty) `logging` submodule so it can be attached alongside the others in
+/// `ImportablePyModuleBuilder`.
+///
+/// Only the first call wins: re-importing the extension module in the same process (e.g. in
+/// tests) must not panic trying to install a second global subscriber.
+pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<pyo3::Bound<'py, pyo3::types::PyModule>> {
+    let _ = tracing_subscriber::registry()
+        .with(PythonLoggingLayer.with_filter(LevelFilter::TRACE))
+        .try_init();
+
+    let module = ImportablePyModuleBuilder::new(py, name)?.defaults()?.finish();
+    Ok(module)
+}
@@ -0,0 +1,198 @@
+use crate::environ::{EnvironmentError, TryFromEnv};
+use crate::{EndpointResult, Error};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::time::sleep;
+use tracing::debug;
+
+/// Knobs for the micro-batching stage that sits in front of `wait_for_requests`: how many
+/// individually-arriving requests may be coalesced into one call to the handler, and how long to
+/// wait for a batch to fill before flushing whatever has accumulated so far.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct BatchingPolicy {
+    /// Maximum number of requests folded into a single handler call.
+    pub max_batch_size: usize,
+
+    /// How long a batch is allowed to accumulate requests before it is flushed, even if
+    /// `max_batch_size` hasn't been reached yet.
+    pub max_latency: Duration,
+}
+
+impl Default for BatchingPolicy {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 8,
+            max_latency: Duration::from_millis(10),
+        }
+    }
+}
+
+impl TryFromEnv for BatchingPolicy {
+    /// The name of the environment variable used to configure the maximum number of requests
+    /// folded into a single batch. Defaults to `8` when unset.
+    const ENV_VAR_NAME: &'static str = "HFENDPOINTS_MAX_BATCH_SIZE";
+
+    fn try_from_env() -> Result<Self, EnvironmentError>
+    where
+        Self: Sized,
+    {
+        let defaults = Self::default();
+
+        let max_batch_size = match std::env::var(Self::ENV_VAR_NAME) {
+            Ok(value) => usize::from_str(&value).map_err(|e| {
+                EnvironmentError::InvalidEnvVar(Self::ENV_VAR_NAME.into(), e.to_string())
+            })?,
+            Err(_) => defaults.max_batch_size,
+        };
+
+        const MAX_BATCH_LATENCY_ENV_VAR_NAME: &str = "HFENDPOINTS_MAX_BATCH_LATENCY_MS";
+        let max_latency = match std::env::var(MAX_BATCH_LATENCY_ENV_VAR_NAME) {
+            Ok(value) => Duration::from_millis(u64::from_str(&value).map_err(|e| {
+                EnvironmentError::InvalidEnvVar(MAX_BATCH_LATENCY_ENV_VAR_NAME.into(), e.to_string())
+            })?),
+            Err(_) => defaults.max_latency,
+        };
+
+        Ok(Self {
+            max_batch_size,
+            max_latency,
+        })
+    }
+}
+
+/// Coalesces many individually-arriving requests into one combined request before it reaches
+/// `Handler::on_request`, then carves the combined response back into one response per original
+/// caller. Plugged into [`batch_requests`] as a trait object the same way `Middleware<Req, Res>`
+/// plugs into `wait_for_requests`, so heterogeneous request/response shapes can each provide their
+/// own coalescing logic without `batch_requests` knowing anything about them.
+pub trait Batcher<Req, Res>: Send + Sync {
+    /// Whether `a` and `b` may be coalesced into the same [`merge`](Self::merge) call. Defaults
+    /// to always compatible, matching every batcher's behavior before this hook existed. A
+    /// batcher whose requests carry settings that must not be mixed (e.g. different model
+    /// parameters) overrides this so mismatched requests are split into separate merged calls by
+    /// [`batch_requests`] instead of one request's settings silently winning for the whole batch.
+    fn compatible(&self, a: &Req, b: &Req) -> bool {
+        let _ = (a, b);
+        true
+    }
+
+    /// Combine `requests` (at least one, in arrival order, all mutually [`compatible`](Self::compatible))
+    /// into a single request to dispatch, alongside how many response rows each original request
+    /// should receive back once the combined response comes in -- e.g. a request that itself
+    /// carried a batch of `n` inputs expects `n` rows out of the combined response. Must return as
+    /// many sizes as `requests` had elements, in the same order.
+    fn merge(&self, requests: Vec<Req>) -> (Req, Vec<usize>);
+
+    /// Split a combined response back into one response per original request, using the row
+    /// counts `merge` returned. Must return exactly as many responses as `group_sizes` has
+    /// entries, in the same order.
+    fn split(&self, response: Res, group_sizes: Vec<usize>) -> Vec<Res>;
+}
+
+/// Sits in front of `wait_for_requests`: accumulates individually-arriving requests from
+/// `ingress` until either `policy.max_batch_size` is reached or `policy.max_latency` elapses
+/// since the batch's first request arrived, whichever comes first, then forwards one request
+/// coalesced by `batcher` onto `egress`. The coalesced response is split back apart and fanned out
+/// to each original caller's own `UnboundedSender`, exactly as if it had been handled alone.
+pub async fn batch_requests<I, O>(
+    mut ingress: UnboundedReceiver<(I, UnboundedSender<EndpointResult<O>>)>,
+    egress: UnboundedSender<(I, UnboundedSender<EndpointResult<O>>)>,
+    batcher: Arc<dyn Batcher<I, O>>,
+    policy: BatchingPolicy,
+) where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    'looper: loop {
+        let Some((first_request, first_caller)) = ingress.recv().await else {
+            break 'looper;
+        };
+
+        let mut requests = vec![first_request];
+        let mut callers = vec![first_caller];
+
+        let deadline = sleep(policy.max_latency);
+        tokio::pin!(deadline);
+
+        while requests.len() < policy.max_batch_size {
+            tokio::select! {
+                _ = &mut deadline => break,
+                received = ingress.recv() => match received {
+                    Some((request, caller)) => {
+                        requests.push(request);
+                        callers.push(caller);
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        debug!("[BATCHER] flushing batch of {} request(s)", requests.len());
+
+        // Split the flushed batch into runs the batcher actually considers compatible, so e.g.
+        // two concurrent requests with different parameters each get their own `merge` call
+        // instead of one silently adopting the other's settings. Assignment is computed against
+        // each run's first (representative) request, preserving arrival order within and across
+        // runs; a batcher that never overrides `compatible` keeps everything in one run, exactly
+        // as before this split existed.
+        let mut representatives: Vec<&I> = Vec::new();
+        let assignments: Vec<usize> = requests
+            .iter()
+            .map(|request| {
+                match representatives
+                    .iter()
+                    .position(|representative| batcher.compatible(representative, request))
+                {
+                    Some(run) => run,
+                    None => {
+                        representatives.push(request);
+                        representatives.len() - 1
+                    }
+                }
+            })
+            .collect();
+
+        let run_count = assignments.iter().copied().max().map_or(0, |max| max + 1);
+        let mut runs: Vec<Vec<I>> = (0..run_count).map(|_| Vec::new()).collect();
+        let mut run_callers: Vec<Vec<UnboundedSender<EndpointResult<O>>>> =
+            (0..run_count).map(|_| Vec::new()).collect();
+        for ((request, caller), run) in requests
+            .into_iter()
+            .zip(callers)
+            .zip(assignments)
+        {
+            runs[run].push(request);
+            run_callers[run].push(caller);
+        }
+
+        for (requests, callers) in runs.into_iter().zip(run_callers) {
+            let (merged, group_sizes) = batcher.merge(requests);
+            let (sender, mut receiver) = unbounded_channel();
+            if egress.send((merged, sender)).is_err() {
+                break 'looper;
+            }
+
+            match receiver.recv().await {
+                Some(Ok(response)) => {
+                    for (caller, response) in
+                        callers.into_iter().zip(batcher.split(response, group_sizes))
+                    {
+                        let _ = caller.send(Ok(response));
+                    }
+                }
+                Some(Err(error)) => {
+                    // `Error` doesn't implement `Clone`, so every sibling but the first gets a
+                    // `Runtime` error carrying the same message rather than the original variant.
+                    let message = error.to_string();
+                    for caller in callers {
+                        let _ = caller.send(Err(Error::Runtime(message.clone().into())));
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::handler::HandlerError::IpcFailed;
+use crate::retry::{backoff_for, retry_strategy_for, RetryStrategy, MAX_ATTEMPTS};
 use crate::{EndpointResult, Error};
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 
@@ -25,6 +26,39 @@ impl<I, O> EndpointContext<I, O> {
 
         Ok(receiver)
     }
+}
+
+impl<I: Clone, O> EndpointContext<I, O> {
+    /// Like [`schedule`](Self::schedule), but rides out transient handler errors instead of
+    /// failing on the first one: the request is re-sent with an exponential backoff
+    /// ([`backoff_for`]) whenever [`retry_strategy_for`] classifies the error as recoverable, up
+    /// to [`MAX_ATTEMPTS`] attempts, after which the last error is returned as-is.
+    pub async fn schedule_with_retry(
+        &self,
+        request: I,
+    ) -> EndpointResult<UnboundedReceiver<EndpointResult<O>>> {
+        let mut attempt = 0;
+        loop {
+            let mut receiver = self.schedule(request.clone())?;
+            match receiver.recv().await {
+                Some(Err(error)) => {
+                    let strategy = retry_strategy_for(&error);
+                    if strategy == RetryStrategy::GiveUp || attempt + 1 >= MAX_ATTEMPTS {
+                        return Err(error);
+                    }
+
+                    tokio::time::sleep(backoff_for(strategy, attempt)).await;
+                    attempt += 1;
+                }
+                Some(Ok(response)) => {
+                    let (sender, replay) = unbounded_channel();
+                    let _ = sender.send(Ok(response));
+                    return Ok(replay);
+                }
+                None => return Ok(receiver),
+            }
+        }
+    }
 
     // ///
     // ///
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Clone, Error, Debug)]
@@ -30,3 +31,59 @@ pub trait TryFromEnv {
     where
         Self: Sized;
 }
+
+/// Admission-control knobs for `wait_for_requests`: how many requests may be handled
+/// concurrently, and how many more may wait for a slot before new requests are rejected
+/// outright.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct Concurrency {
+    /// Maximum number of requests `wait_for_requests` runs through the handler at once.
+    pub max_in_flight: usize,
+
+    /// Maximum number of requests allowed to wait for an in-flight slot to free up. Requests
+    /// beyond this bound are rejected immediately instead of growing the queue indefinitely.
+    pub max_queue: usize,
+}
+
+impl Default for Concurrency {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 32,
+            max_queue: 128,
+        }
+    }
+}
+
+impl TryFromEnv for Concurrency {
+    /// The name of the environment variable used to configure the maximum number of requests
+    /// handled concurrently. Defaults to `32` when unset.
+    const ENV_VAR_NAME: &'static str = "HFENDPOINTS_MAX_IN_FLIGHT";
+
+    fn try_from_env() -> Result<Self, EnvironmentError>
+    where
+        Self: Sized,
+    {
+        let defaults = Self::default();
+
+        let max_in_flight = match std::env::var(Self::ENV_VAR_NAME) {
+            Ok(value) => usize::from_str(&value).map_err(|e| {
+                EnvironmentError::InvalidEnvVar(Self::ENV_VAR_NAME.into(), e.to_string())
+            })?,
+            Err(_) => defaults.max_in_flight,
+        };
+
+        const MAX_QUEUE_ENV_VAR_NAME: &str = "HFENDPOINTS_MAX_QUEUE";
+        let max_queue = match std::env::var(MAX_QUEUE_ENV_VAR_NAME) {
+            Ok(value) => usize::from_str(&value).map_err(|e| {
+                EnvironmentError::InvalidEnvVar(MAX_QUEUE_ENV_VAR_NAME.into(), e.to_string())
+            })?,
+            Err(_) => defaults.max_queue,
+        };
+
+        Ok(Self {
+            max_in_flight,
+            max_queue,
+        })
+    }
+}
@@ -1,10 +1,20 @@
+use crate::environ::Concurrency;
+use crate::health::HealthReporter;
+use crate::middleware::{Middleware, MiddlewareOutcome};
 use crate::Error;
 use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::spawn;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::sync::Semaphore;
 use tracing::{debug, error, span, warn, Instrument, Level};
 
+/// Sending half of the channel a streaming [`Handler::on_stream_request`] implementation pushes
+/// intermediate responses through, before resolving with the final one exactly as
+/// [`Handler::on_request`] would.
+pub type PartialSender<O> = UnboundedSender<Result<O, Error>>;
+
 #[derive(Clone, Debug, Error)]
 pub enum HandlerError {
     #[error("Failed to send message through IPC: {0}")]
@@ -13,6 +23,11 @@ pub enum HandlerError {
     #[cfg(feature = "python")]
     #[error("Python handler implementation is not correct: {0}")]
     Implementation(Cow<'static, str>),
+
+    /// Too many requests are already waiting for an in-flight slot; raised by
+    /// `wait_for_requests` once its `Concurrency::max_queue` bound is exceeded.
+    #[error("Too many requests in flight, please retry later")]
+    Overloaded,
 }
 
 /// A trait that represents a generic handler for processing requests asynchronously.
@@ -106,25 +121,98 @@ pub trait Handler {
         &self,
         request: Self::Request,
     ) -> impl Future<Output = Result<Self::Response, Error>> + Send;
+
+    /// Like `on_request`, but lets the implementation push intermediate responses through
+    /// `partial` as they become available, before resolving with the final response exactly as
+    /// `on_request` would (e.g. a transcription handler emitting one response per decoded
+    /// `Segment`, followed by the final, complete transcription).
+    ///
+    /// The default implementation never sends anything through `partial` and just delegates to
+    /// `on_request`, so handlers that only ever produce one response don't need to change.
+    fn on_stream_request(
+        &self,
+        request: Self::Request,
+        _partial: PartialSender<Self::Response>,
+    ) -> impl Future<Output = Result<Self::Response, Error>> + Send {
+        self.on_request(request)
+    }
+}
+
+/// Folds `request` through `middlewares`, in order, stopping at the first stage that
+/// short-circuits with a response.
+async fn run_middlewares<I, O>(
+    middlewares: &[Arc<dyn Middleware<I, O>>],
+    mut request: I,
+) -> Result<MiddlewareOutcome<I, O>, Error> {
+    for middleware in middlewares {
+        match middleware.on_request(request).await? {
+            MiddlewareOutcome::Continue(next) => request = next,
+            respond @ MiddlewareOutcome::Respond(_) => return Ok(respond),
+        }
+    }
+    Ok(MiddlewareOutcome::Continue(request))
 }
 
 pub async fn wait_for_requests<I, O, H>(
     mut ingress: UnboundedReceiver<(I, UnboundedSender<Result<O, Error>>)>,
     background_handler: Arc<H>,
+    middlewares: Vec<Arc<dyn Middleware<I, O>>>,
+    concurrency: Concurrency,
+    health: Arc<HealthReporter>,
 ) where
     I: Send + 'static,
     O: Send + 'static,
     H: Handler<Request = I, Response = O> + Send + Sync + 'static,
 {
+    // Bounds how many requests run through the handler at once; a permit is acquired before
+    // spawning `on_request` and held until the response is sent on `egress`.
+    let permits = Arc::new(Semaphore::new(concurrency.max_in_flight));
+    // Tracks requests that have been spawned but are still waiting for a permit, so a burst
+    // beyond `max_queue` can be rejected outright instead of growing the queue indefinitely.
+    let queued = Arc::new(AtomicUsize::new(0));
+
     'looper: loop {
         if let Some((request, egress)) = ingress.recv().await {
             debug!("[LOOPER] Received request");
+
+            if queued.fetch_add(1, Ordering::SeqCst) >= concurrency.max_queue {
+                queued.fetch_sub(1, Ordering::SeqCst);
+                warn!("[LOOPER] max_queue exceeded, rejecting request");
+                if let Err(e) = egress.send(Err(HandlerError::Overloaded.into())) {
+                    error!("Failed to send back response to client: {e}");
+                }
+                continue 'looper;
+            }
+
             let background_handler = Arc::clone(&background_handler);
+            let middlewares = middlewares.clone();
+            let permits = Arc::clone(&permits);
+            let queued = Arc::clone(&queued);
+            let health = Arc::clone(&health);
             let sp_on_request = span!(Level::DEBUG, "on_request");
 
             spawn(
                 async move {
-                    let response = background_handler.on_request(request).await;
+                    let permit = permits
+                        .acquire_owned()
+                        .await
+                        .expect("semaphore is never closed");
+                    let in_queue = queued.fetch_sub(1, Ordering::SeqCst) as u32 - 1;
+
+                    let response = match run_middlewares(&middlewares, request).await {
+                        Ok(MiddlewareOutcome::Respond(response)) => Ok(response),
+                        Ok(MiddlewareOutcome::Continue(request)) => {
+                            let partial = egress.clone();
+                            background_handler
+                                .on_stream_request(request, partial)
+                                .await
+                        }
+                        Err(err) => Err(err),
+                    };
+                    drop(permit);
+
+                    health.record(in_queue, response.is_ok());
+
                     if let Err(e) = egress.send(response) {
                         error!("Failed to send back response to client: {e}");
                     }
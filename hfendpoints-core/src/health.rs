@@ -0,0 +1,125 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// How quickly [`HealthReporter`]'s error rate forgets past outcomes: the latest outcome is
+/// weighted at 10% and the rest decays, so a handler that was failing and has since recovered
+/// stops looking unhealthy within a handful of requests instead of being dragged down by its
+/// entire history.
+const ERROR_RATE_SMOOTHING: f64 = 0.1;
+
+/// Point-in-time view of a background handler's health, pushed over a [`watch`] channel so the
+/// HTTP layer can answer `/health` probes without touching the IPC path requests flow through.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone)]
+pub struct HealthSnapshot {
+    /// Requests queued ahead of the handler at the time this snapshot was taken.
+    pub in_queue: u32,
+    /// When the handler last completed a request successfully, if ever.
+    pub last_success: Option<Instant>,
+    /// Exponential moving average of the error rate across recently completed requests.
+    pub error_rate: f64,
+    reported_at: Instant,
+}
+
+impl Default for HealthSnapshot {
+    fn default() -> Self {
+        Self {
+            in_queue: 0,
+            last_success: None,
+            error_rate: 0.0,
+            reported_at: Instant::now(),
+        }
+    }
+}
+
+impl HealthSnapshot {
+    /// A handler is considered live if it pushed a snapshot within `within` of now; a handler
+    /// that panicked or got wedged stops reporting altogether and falls out of this window.
+    pub fn is_live(&self, within: Duration) -> bool {
+        self.reported_at.elapsed() <= within
+    }
+}
+
+/// Handler-side half of the health channel: tracks a rolling error rate and publishes a fresh
+/// [`HealthSnapshot`] after every completed request.
+pub struct HealthReporter {
+    tx: watch::Sender<HealthSnapshot>,
+    error_rate: Mutex<f64>,
+}
+
+impl HealthReporter {
+    /// Creates a reporter paired with the [`watch::Receiver`] the HTTP layer polls for
+    /// readiness. Cloning the receiver is cheap, so every caller that wants to observe liveness
+    /// can hold its own copy.
+    pub fn new() -> (Self, watch::Receiver<HealthSnapshot>) {
+        let (tx, rx) = watch::channel(HealthSnapshot::default());
+        let reporter = Self {
+            tx,
+            error_rate: Mutex::new(0.0),
+        };
+        (reporter, rx)
+    }
+
+    /// Records a completed request's outcome and current queue depth, updating the rolling
+    /// error rate and publishing a fresh snapshot.
+    pub fn record(&self, in_queue: u32, succeeded: bool) {
+        let mut error_rate = self
+            .error_rate
+            .lock()
+            .expect("health reporter mutex poisoned");
+        let outcome = if succeeded { 0.0 } else { 1.0 };
+        *error_rate = ERROR_RATE_SMOOTHING * outcome + (1.0 - ERROR_RATE_SMOOTHING) * *error_rate;
+
+        let last_success = if succeeded {
+            Some(Instant::now())
+        } else {
+            self.tx.borrow().last_success
+        };
+
+        self.tx.send_replace(HealthSnapshot {
+            in_queue,
+            last_success,
+            error_rate: *error_rate,
+            reported_at: Instant::now(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fresh_snapshot_is_live() {
+        let snapshot = HealthSnapshot::default();
+        assert!(snapshot.is_live(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_stale_snapshot_is_not_live() {
+        let snapshot = HealthSnapshot::default();
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(!snapshot.is_live(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn test_record_success_updates_last_success_and_decays_error_rate() {
+        let (reporter, rx) = HealthReporter::new();
+        reporter.record(0, false);
+        assert!(rx.borrow().last_success.is_none());
+        assert!(rx.borrow().error_rate > 0.0);
+
+        reporter.record(0, true);
+        let snapshot = rx.borrow();
+        assert!(snapshot.last_success.is_some());
+        assert!(snapshot.error_rate < 0.1);
+    }
+
+    #[test]
+    fn test_record_tracks_queue_depth() {
+        let (reporter, rx) = HealthReporter::new();
+        reporter.record(7, true);
+        assert_eq!(rx.borrow().in_queue, 7);
+    }
+}
@@ -1,14 +1,24 @@
+mod batching;
 mod context;
 mod endpoint;
 pub mod environ;
 mod handler;
+mod health;
 mod metrics;
+mod middleware;
+mod retry;
+mod validation;
 
+pub use batching::{batch_requests, Batcher, BatchingPolicy};
 pub use crate::handler::HandlerError;
 pub use context::EndpointContext;
 pub use endpoint::Endpoint;
-pub use handler::{wait_for_requests, Handler};
+pub use handler::{wait_for_requests, Handler, PartialSender};
+pub use health::{HealthReporter, HealthSnapshot};
 pub use metrics::InFlightStats;
+pub use middleware::{Middleware, MiddlewareError, MiddlewareOutcome};
+pub use retry::{backoff_for, retry_strategy_for, RetryStrategy, MAX_ATTEMPTS};
+pub use validation::{Validate, ValidationError, ValidationErrors};
 use std::borrow::Cow;
 
 use crate::environ::EnvironmentError;
@@ -25,6 +35,12 @@ pub enum Error {
     #[error("{0}")]
     Handler(#[from] HandlerError),
 
+    #[error("{0}")]
+    Middleware(#[from] MiddlewareError),
+
+    #[error("{0}")]
+    Validation(#[from] ValidationErrors),
+
     #[error("{0}")]
     Runtime(Cow<'static, str>),
 
@@ -1,15 +1,73 @@
 use serde::Serialize;
-use std::sync::atomic::AtomicU32;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+/// Live saturation counters for an endpoint: how many requests are currently queued or being
+/// handled, and the highest either has ever reached. Updated by a transport's accept/dispatch
+/// path (e.g. `hfendpoints_http`'s metrics tower layer) and exported as-is over `/metrics`.
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(feature = "python", pyclass(frozen))]
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 pub struct InFlightStats {
     in_flight: AtomicU32,
     in_queue: AtomicU32,
     max_in_flight: AtomicU32,
     max_in_queue: AtomicU32,
 }
+
+/// Bumps `value` by one and updates `max` to follow it, retrying the compare-and-swap until it
+/// succeeds or `max` is already at least as large as the new `value`.
+fn increment_and_track_max(value: &AtomicU32, max: &AtomicU32) -> u32 {
+    let updated = value.fetch_add(1, Ordering::AcqRel) + 1;
+
+    let mut observed_max = max.load(Ordering::Acquire);
+    while observed_max < updated {
+        match max.compare_exchange_weak(
+            observed_max,
+            updated,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => break,
+            Err(current) => observed_max = current,
+        }
+    }
+
+    updated
+}
+
+impl InFlightStats {
+    pub fn in_flight(&self) -> u32 {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    pub fn in_queue(&self) -> u32 {
+        self.in_queue.load(Ordering::Acquire)
+    }
+
+    pub fn max_in_flight(&self) -> u32 {
+        self.max_in_flight.load(Ordering::Acquire)
+    }
+
+    pub fn max_in_queue(&self) -> u32 {
+        self.max_in_queue.load(Ordering::Acquire)
+    }
+
+    /// A request was accepted and is waiting to be dispatched to a handler.
+    pub fn enqueue(&self) {
+        increment_and_track_max(&self.in_queue, &self.max_in_queue);
+    }
+
+    /// A queued request is now being handled.
+    pub fn dequeue_to_in_flight(&self) {
+        self.in_queue.fetch_sub(1, Ordering::AcqRel);
+        increment_and_track_max(&self.in_flight, &self.max_in_flight);
+    }
+
+    /// A request finished, successfully or not.
+    pub fn complete(&self) {
+        self.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+
+/// What running a single [`Middleware`] stage did to a request.
+pub enum MiddlewareOutcome<Req, Res> {
+    /// Carry on to the next stage (or to `Handler::on_request`) with this request, left
+    /// untouched or replaced by the middleware.
+    Continue(Req),
+
+    /// Short-circuit the pipeline: this response is returned immediately without ever reaching
+    /// `Handler::on_request` or any later middleware.
+    Respond(Res),
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum MiddlewareError {
+    /// A middleware explicitly rejected the request (e.g. Python's `MiddlewareException`),
+    /// optionally naming a status code for transports that have a concept of one.
+    #[error("Request rejected by middleware: {0}")]
+    Rejected(Cow<'static, str>, Option<u16>),
+
+    /// Anything else a middleware raised while processing a request.
+    #[error("Middleware failed: {0}")]
+    Failed(Cow<'static, str>),
+}
+
+/// One stage of an ordered pipeline that runs ahead of `Handler::on_request`, following the
+/// smithy-rs request-middleware model: it may leave the request as-is, replace it for the next
+/// stage, or short-circuit with a response of its own.
+///
+/// The method returns a boxed future rather than `impl Future` so that a pipeline can be stored
+/// as `Vec<Arc<dyn Middleware<Req, Res>>>` and run generically over heterogeneous
+/// implementations, e.g. `wait_for_requests`'s per-request fold.
+pub trait Middleware<Req, Res>: Send + Sync {
+    fn on_request(
+        &self,
+        request: Req,
+    ) -> Pin<Box<dyn Future<Output = Result<MiddlewareOutcome<Req, Res>, MiddlewareError>> + Send>>;
+}
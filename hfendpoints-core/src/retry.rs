@@ -0,0 +1,80 @@
+use crate::handler::HandlerError;
+use crate::Error;
+use std::time::Duration;
+
+/// How `schedule_with_retry` should react to a recoverable `EndpointResult` error.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// The error isn't transient; surface it to the caller immediately.
+    GiveUp,
+    /// Retry after a generic exponential backoff.
+    Retry,
+    /// Retry after a longer backoff reserved for rate-limit-style rejections.
+    RetryAfterRateLimit,
+}
+
+/// Caps how many times `schedule_with_retry` will re-send a request before giving up and
+/// surfacing the last error, so a persistently failing handler can't stall the caller forever.
+pub const MAX_ATTEMPTS: u32 = 5;
+
+/// Classifies an `EndpointResult` error into a [`RetryStrategy`]. Only errors known to be
+/// transient -- a dropped IPC send, or `wait_for_requests` rejecting under `max_queue` pressure --
+/// are retried; everything else (validation failures, handler bugs, ...) gives up immediately
+/// since resending the same request would just fail the same way.
+pub fn retry_strategy_for(error: &Error) -> RetryStrategy {
+    match error {
+        Error::Handler(HandlerError::Overloaded) => RetryStrategy::RetryAfterRateLimit,
+        Error::Handler(HandlerError::IpcFailed(_)) => RetryStrategy::Retry,
+        _ => RetryStrategy::GiveUp,
+    }
+}
+
+/// Computes how long to sleep before the next attempt: roughly `10^attempt` milliseconds for a
+/// generic retry, `100 + 10^attempt` ms when backing off from a rate-limit-style rejection.
+/// `attempt` is capped before exponentiating so a long run of retries can't overflow.
+pub fn backoff_for(strategy: RetryStrategy, attempt: u32) -> Duration {
+    let exponential = 10u64.saturating_pow(attempt.min(9));
+    match strategy {
+        RetryStrategy::GiveUp => Duration::ZERO,
+        RetryStrategy::Retry => Duration::from_millis(exponential),
+        RetryStrategy::RetryAfterRateLimit => Duration::from_millis(100 + exponential),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_strategy_for_overloaded_is_rate_limited() {
+        let error = Error::Handler(HandlerError::Overloaded);
+        assert_eq!(retry_strategy_for(&error), RetryStrategy::RetryAfterRateLimit);
+    }
+
+    #[test]
+    fn test_retry_strategy_for_ipc_failure_retries() {
+        let error = Error::Handler(HandlerError::IpcFailed("closed".into()));
+        assert_eq!(retry_strategy_for(&error), RetryStrategy::Retry);
+    }
+
+    #[test]
+    fn test_retry_strategy_for_other_errors_gives_up() {
+        let error = Error::Runtime("boom".into());
+        assert_eq!(retry_strategy_for(&error), RetryStrategy::GiveUp);
+    }
+
+    #[test]
+    fn test_backoff_for_retry_is_exponential() {
+        assert_eq!(backoff_for(RetryStrategy::Retry, 0), Duration::from_millis(1));
+        assert_eq!(backoff_for(RetryStrategy::Retry, 2), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_backoff_for_rate_limit_adds_base_delay() {
+        assert_eq!(
+            backoff_for(RetryStrategy::RetryAfterRateLimit, 0),
+            Duration::from_millis(101)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// A single field that failed validation, with a human-readable reason.
+#[derive(Clone, Debug, Error)]
+#[error("{field}: {message}")]
+pub struct ValidationError {
+    pub field: Cow<'static, str>,
+    pub message: Cow<'static, str>,
+}
+
+impl ValidationError {
+    pub fn new(
+        field: impl Into<Cow<'static, str>>,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// One or more [`ValidationError`]s collected while validating a request, surfaced together so
+/// a caller doesn't have to fix and resubmit one field at a time.
+#[derive(Clone, Debug, Error, Default)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl ValidationErrors {
+    pub fn push(&mut self, error: ValidationError) {
+        self.0.push(error);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Folds `self` into `Ok(())` when empty, `Err(self)` otherwise -- the usual shape a
+    /// `validate()` implementation builds up a `ValidationErrors` in and returns at the end.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+/// Implemented by request types (and the constrained field wrappers composing them) that can
+/// check their own invariants ahead of reaching a [`crate::Handler`]. Modeled on smithy-rs
+/// server constraint shapes: a constrained type validates itself, and a composite request
+/// validates by delegating to its fields.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+macro_rules! impl_validate_noop {
+    ($($ty: ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                #[inline]
+                fn validate(&self) -> Result<(), ValidationErrors> {
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+// Plain, unconstrained field types have nothing to check; this lets composite requests derive
+// their `Validate` by delegating to every field uniformly instead of special-casing the ones
+// with no constraints of their own.
+impl_validate_noop!(String, bool, usize, isize, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        for item in self {
+            if let Err(e) = item.validate() {
+                errors.0.extend(e.0);
+            }
+        }
+        errors.into_result()
+    }
+}
@@ -0,0 +1,26 @@
+use hfendpoints_core::environ::EnvironmentError;
+use hfendpoints_core::Error as EndpointError;
+use std::io;
+use thiserror::Error;
+
+/// Define all the possible errors for the gRPC transport, mirroring
+/// `hfendpoints_http::HttpError`'s shape for the REST one.
+#[derive(Debug, Error)]
+pub enum GrpcError {
+    #[error("Endpoint error: {0}")]
+    Endpoint(#[from] EndpointError),
+
+    #[error("{0}")]
+    Environment(#[from] EnvironmentError),
+
+    #[error("I/O error occurred: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("gRPC transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    #[error("Failed to build the server-reflection service: {0}")]
+    Reflection(#[from] tonic_reflection::server::Error),
+}
+
+pub type GrpcResult<T> = Result<T, GrpcError>;
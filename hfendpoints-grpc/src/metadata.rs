@@ -0,0 +1,79 @@
+use http::{HeaderValue, Request, Response};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context as TaskContext, Poll};
+use tower::{Layer, Service};
+use uuid::Uuid;
+
+/// The same header name `hfendpoints_http` correlates requests by, reused here as the gRPC
+/// metadata key so a caller's `x-request-id` means the same thing on both transports.
+pub static X_REQUEST_ID: &str = "x-request-id";
+
+/// Tower layer mirroring `hfendpoints_http`'s `SetRequestIdLayer`/`PropagateRequestIdLayer` pair
+/// for gRPC: stamps an `x-request-id` metadata entry on requests that don't already carry one,
+/// and copies it back onto the response so request-correlation behaves the same way whether a
+/// caller comes in over REST or gRPC.
+#[derive(Clone, Default)]
+pub struct MetadataLayer;
+
+impl<S> Layer<S> for MetadataLayer {
+    type Service = MetadataService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetadataService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetadataService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for MetadataService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let request_id = request
+            .headers()
+            .get(X_REQUEST_ID)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let mut inner = self.inner.clone();
+
+        match HeaderValue::from_str(&request_id) {
+            Ok(value) => {
+                request.headers_mut().insert(X_REQUEST_ID, value.clone());
+                Box::pin(async move {
+                    let mut response = inner.call(request).await?;
+                    response.headers_mut().insert(X_REQUEST_ID, value);
+                    Ok(response)
+                })
+            }
+            // A request-id that doesn't round-trip through a header value can't be propagated,
+            // but shouldn't block the call either.
+            Err(_) => Box::pin(async move { inner.call(request).await }),
+        }
+    }
+}
+
+/// Reads the request-correlation id a [`MetadataLayer`]-wrapped service stamped onto an incoming
+/// gRPC call, for handlers that need to thread it through to `Handler::on_request` the same way
+/// `hfendpoints_http`'s routes do with `TypedHeader<RequestId>`.
+pub fn request_id<T>(request: &tonic::Request<T>) -> Option<&str> {
+    request
+        .metadata()
+        .get(X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+}
@@ -0,0 +1,14 @@
+use tonic_reflection::server::{ServerReflection, ServerReflectionServer};
+
+/// Build the v1 server-reflection service so gRPC clients can discover the services a
+/// [`crate::serve_grpc`] endpoint exposes without shipping `.proto` files out of band.
+///
+/// Real `.proto` contracts compiled through `tonic-build` embed a `FileDescriptorSet` at build
+/// time (conventionally via `include_bytes!(concat!(env!("OUT_DIR"), "/descriptor.bin"))`) and
+/// register it here with `.register_encoded_file_descriptor_set(...)`. Until the first gRPC
+/// service crate adds that build step, this registers none, so reflection only ever advertises
+/// the always-on health-check service.
+pub fn build() -> Result<ServerReflectionServer<impl ServerReflection>, tonic_reflection::server::Error>
+{
+    tonic_reflection::server::Builder::configure().build_v1()
+}
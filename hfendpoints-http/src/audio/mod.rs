@@ -0,0 +1,90 @@
+pub(crate) mod speech;
+pub(crate) mod transcription;
+pub(crate) mod translation;
+
+pub const AUDIO_TAG: &str = "Audio";
+pub const AUDIO_DESC: &str = "Learn how to turn audio into text or text into audio.";
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::audio::speech::{AudioFormat, SpeechRequest, SpeechResponse};
+    use crate::audio::transcription::python::TranscriptionResponseKind;
+    use crate::audio::transcription::{
+        ResponseFormat, Segment, SpeechContext, TimestampGranularity, Transcription,
+        TranscriptionRequest, TranscriptionResponse, VerboseTranscription, Word,
+    };
+    use crate::audio::translation::TranslationRequest;
+    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use pyo3::prelude::*;
+
+    mod transcriptions {
+        use crate::audio::transcription::{
+            TranscriptionRequest, TranscriptionResponse, TranscriptionRouter,
+        };
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(TranscriptionRequest, TranscriptionResponse);
+        impl_http_pyendpoint!(
+            "TranscriptionEndpoint",
+            PyTranscriptionEndpoint,
+            PyHandler,
+            TranscriptionRouter
+        );
+    }
+
+    mod speeches {
+        use crate::audio::speech::{SpeechRequest, SpeechResponse, SpeechRouter};
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(SpeechRequest, SpeechResponse);
+        impl_http_pyendpoint!(
+            "SpeechEndpoint",
+            PySpeechEndpoint,
+            PyHandler,
+            SpeechRouter
+        );
+    }
+
+    mod translations {
+        use crate::audio::transcription::TranscriptionResponse;
+        use crate::audio::translation::{TranslationRequest, TranslationRouter};
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(TranslationRequest, TranscriptionResponse);
+        impl_http_pyendpoint!(
+            "TranslationEndpoint",
+            PyTranslationEndpoint,
+            PyHandler,
+            TranslationRouter
+        );
+    }
+
+    /// Bind hfendpoints.http.audio submodule into the exported Python wheel
+    pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
+        let module = ImportablePyModuleBuilder::new(py, name)?
+            .defaults()?
+            // transcription
+            .add_class::<Segment>()?
+            .add_class::<Word>()?
+            .add_class::<SpeechContext>()?
+            .add_class::<Transcription>()?
+            .add_class::<VerboseTranscription>()?
+            .add_class::<TimestampGranularity>()?
+            .add_class::<ResponseFormat>()?
+            .add_class::<TranscriptionRequest>()?
+            .add_class::<TranscriptionResponse>()?
+            .add_class::<TranscriptionResponseKind>()?
+            .add_class::<transcriptions::PyTranscriptionEndpoint>()?
+            // speech
+            .add_class::<AudioFormat>()?
+            .add_class::<SpeechRequest>()?
+            .add_class::<SpeechResponse>()?
+            .add_class::<speeches::PySpeechEndpoint>()?
+            // translation
+            .add_class::<TranslationRequest>()?
+            .add_class::<translations::PyTranslationEndpoint>()?
+            .finish();
+
+        Ok(module)
+    }
+}
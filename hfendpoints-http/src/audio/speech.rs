@@ -0,0 +1,148 @@
+use crate::audio::AUDIO_TAG;
+use crate::context::Context;
+use crate::environ::Timeout;
+use crate::headers::RequestId;
+use crate::{HttpError, HttpResult, RequestWithContext};
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::TypedHeader;
+use hfendpoints_core::environ::TryFromEnv;
+use hfendpoints_core::{EndpointContext, EndpointResult};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Container/codec the synthesized speech should be encoded as.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    Mp3,
+    Wav,
+    Opus,
+    Flac,
+    Pcm,
+}
+
+impl AudioFormat {
+    const fn content_type(self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Wav => "audio/wav",
+            Self::Opus => "audio/opus",
+            Self::Flac => "audio/flac",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// Inputs for the text-to-speech endpoint.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Deserialize, ToSchema)]
+pub struct SpeechRequest {
+    /// The text to generate audio for.
+    pub input: String,
+
+    /// The voice to synthesize the audio with.
+    pub voice: String,
+
+    /// The format the synthesized audio should be encoded as.
+    pub response_format: AudioFormat,
+
+    /// The speed of the generated audio, between `0.25` and `4.0`.
+    pub speed: f32,
+}
+
+type SpeechRequestWithContext = RequestWithContext<SpeechRequest>;
+
+/// Encoded audio produced by the text-to-speech model, along with the format it was encoded as.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct SpeechResponse {
+    /// The synthesized audio bytes, encoded per `format`.
+    #[schema(format = Binary)]
+    audio: Vec<u8>,
+
+    /// The format `audio` is encoded as.
+    format: AudioFormat,
+}
+
+impl IntoResponse for SpeechResponse {
+    fn into_response(self) -> Response {
+        (
+            [(header::CONTENT_TYPE, self.format.content_type())],
+            self.audio,
+        )
+            .into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/audio/speech",
+    tag = AUDIO_TAG,
+    request_body(content = SpeechRequest, content_type = "application/json"),
+    responses(
+        (status = OK, description = "Synthesizes speech audio for the input text.", body = SpeechResponse),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn synthesize(
+    State(state): State<EndpointContext<SpeechRequestWithContext, SpeechResponse>>,
+    request_id: TypedHeader<RequestId>,
+    Json(request): Json<SpeechRequest>,
+) -> HttpResult<SpeechResponse> {
+    // Create request context
+    let ctx = Context::new(request_id.0);
+
+    // Ask for the inference thread to handle it and wait for answers
+    let mut egress = state.schedule((request, ctx))?;
+    if let Some(response) = egress.recv().await {
+        Ok(response?)
+    } else {
+        Err(HttpError::NoResponse)
+    }
+}
+
+/// Helper factory to build the text-to-speech endpoint.
+#[derive(Clone)]
+pub struct SpeechRouter(
+    pub  UnboundedSender<(
+        SpeechRequestWithContext,
+        UnboundedSender<EndpointResult<SpeechResponse>>,
+    )>,
+);
+
+impl From<SpeechRouter> for OpenApiRouter {
+    fn from(value: SpeechRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(synthesize))
+            .with_state(EndpointContext::new(value.0))
+            .layer(Timeout::try_from_env().unwrap_or_default().layer_for("audio"))
+    }
+}
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::audio::speech::{AudioFormat, SpeechResponse};
+    use pyo3::prelude::*;
+
+    #[pymethods]
+    impl SpeechResponse {
+        #[new]
+        pub fn new(audio: Vec<u8>, format: AudioFormat) -> Self {
+            Self { audio, format }
+        }
+    }
+}
@@ -1,17 +1,24 @@
 use crate::audio::AUDIO_TAG;
 use crate::context::Context;
+use crate::environ::Timeout;
 use crate::headers::RequestId;
 use crate::{HttpError, HttpResult, RequestWithContext};
 use axum::body::Bytes;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{DefaultBodyLimit, Multipart, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use axum_extra::TypedHeader;
+use futures_util::stream::{self, Stream};
+use hfendpoints_core::environ::TryFromEnv;
 use hfendpoints_core::{EndpointContext, EndpointResult};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::pin::Pin;
 use std::str::FromStr;
-use tokio::sync::mpsc::UnboundedSender;
-use tracing::instrument;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tracing::{debug, instrument, warn};
 use utoipa::ToSchema;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
@@ -157,6 +164,109 @@ impl Segment {
     }
 }
 
+/// Which timestamp granularity the caller asked for via `timestamp_granularities[]`.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    Segment,
+    Word,
+}
+
+/// A single word and the time range it was spoken in, emitted when `timestamp_granularities`
+/// includes `word`.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct Word {
+    /// The text content of the word.
+    word: String,
+
+    /// Start time of the word in seconds.
+    start: f32,
+
+    /// End time of the word in seconds.
+    end: f32,
+}
+
+#[derive(Default)]
+pub struct WordBuilder {
+    word: Option<String>,
+    start: Option<f32>,
+    end: Option<f32>,
+}
+
+impl WordBuilder {
+    pub fn word(mut self, word: String) -> Self {
+        self.word = Some(word);
+        self
+    }
+
+    pub fn start(mut self, start: f32) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn end(mut self, end: f32) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn build(self) -> HttpResult<Word> {
+        Ok(Word {
+            word: self
+                .word
+                .ok_or(HttpError::Validation(String::from("Word::word is not set")))?,
+            start: self
+                .start
+                .ok_or(HttpError::Validation(String::from("Word::start is not set")))?,
+            end: self
+                .end
+                .ok_or(HttpError::Validation(String::from("Word::end is not set")))?,
+        })
+    }
+}
+
+impl Word {
+    pub fn builder() -> WordBuilder {
+        WordBuilder::default()
+    }
+}
+
+/// A weighted list of words/phrases to bias the recognizer towards, for domain vocabulary (product
+/// names, jargon) that a general-purpose model would otherwise mis-transcribe. Modeled after the
+/// phrase-set hinting in Google's Speech-to-Text API, without tying it to any specific engine --
+/// it is up to the backend to pass `phrases`/`boost` through to its own biasing/hotword interface.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct SpeechContext {
+    /// The words or phrases to favor during decoding.
+    phrases: Vec<String>,
+
+    /// How strongly to favor `phrases` over the model's default vocabulary. Larger values bias
+    /// more aggressively, at the risk of false positives.
+    boost: f32,
+}
+
+/// Parses the multipart `phrases` field into a list of phrases: a JSON array of strings if the
+/// field looks like one, otherwise one phrase per non-empty line.
+fn parse_phrases(raw: &str) -> HttpResult<Vec<String>> {
+    let trimmed = raw.trim();
+    if trimmed.starts_with('[') {
+        return serde_json::from_str(trimmed)
+            .map_err(|e| HttpError::Validation(format!("Invalid phrases JSON array: {e}")));
+    }
+
+    Ok(trimmed
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
 /// Represents a transcription response returned by model, based on the provided input.
 #[cfg_attr(feature = "python", pyclass(frozen))]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -181,7 +291,13 @@ pub struct VerboseTranscription {
     language: String,
 
     /// Segments of the transcribed text and their corresponding details.
-    segments: Vec<Segment>,
+    /// Populated only when `timestamp_granularities` included `segment` (the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    segments: Option<Vec<Segment>>,
+
+    /// Word-level timestamps. Populated only when `timestamp_granularities` included `word`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    words: Option<Vec<Word>>,
 }
 
 #[cfg_attr(feature = "python", pyclass(frozen))]
@@ -230,6 +346,8 @@ pub enum ResponseFormat {
     Json,
     Text,
     VerboseJson,
+    Srt,
+    Vtt,
 }
 
 impl Default for ResponseFormat {
@@ -247,6 +365,10 @@ pub enum TranscriptionResponse {
     Json(Transcription),
     Text(String),
     VerboseJson(VerboseTranscription),
+
+    /// One incrementally-decoded `Segment`, sent over `transcribe_stream` as soon as it is
+    /// available. Never produced by the plain HTTP `transcribe` route.
+    Partial(Segment),
 }
 
 impl IntoResponse for TranscriptionResponse {
@@ -257,6 +379,154 @@ impl IntoResponse for TranscriptionResponse {
             TranscriptionResponse::VerboseJson(transcription) => {
                 Json::from(transcription).into_response()
             }
+            TranscriptionResponse::Partial(segment) => Json::from(segment).into_response(),
+        }
+    }
+}
+
+/// Formats `seconds` as a `HH:MM:SS<fraction_separator>mmm` subtitle cue timestamp.
+pub(crate) fn format_cue_timestamp(seconds: f32, fraction_separator: char) -> String {
+    let millis_total = (seconds.max(0.0) * 1000.0).round() as u64;
+    let hours = millis_total / 3_600_000;
+    let minutes = (millis_total / 60_000) % 60;
+    let secs = (millis_total / 1_000) % 60;
+    let millis = millis_total % 1_000;
+    format!("{hours:02}:{minutes:02}:{secs:02}{fraction_separator}{millis:03}")
+}
+
+/// Serializes `segments` into SubRip cue blocks, one per segment, numbered from 1.
+pub(crate) fn segments_to_srt(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                format_cue_timestamp(segment.start, ','),
+                format_cue_timestamp(segment.end, ','),
+                segment.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Serializes `segments` into a WebVTT cue track, preceded by the mandatory `WEBVTT` header.
+pub(crate) fn segments_to_vtt(segments: &[Segment]) -> String {
+    let cues = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                format_cue_timestamp(segment.start, '.'),
+                format_cue_timestamp(segment.end, '.'),
+                segment.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("WEBVTT\n\n{cues}")
+}
+
+/// [`TranscriptionResponse`], encoded per the request's `response_format`; the SRT/VTT subtitle
+/// formats don't fit the JSON-serializable shape above, so they're rendered directly into the
+/// response body instead.
+pub enum EncodedTranscription {
+    Response(TranscriptionResponse),
+    Subtitle(String, &'static str),
+}
+
+impl IntoResponse for EncodedTranscription {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Response(response) => response.into_response(),
+            Self::Subtitle(body, content_type) => {
+                ([(axum::http::header::CONTENT_TYPE, content_type)], body).into_response()
+            }
+        }
+    }
+}
+
+/// The text carried by a (possibly non-final) [`TranscriptionResponse`], used to build the
+/// `transcript.text.done` event once the handler's egress channel is exhausted.
+pub(crate) fn transcription_text(response: &TranscriptionResponse) -> String {
+    match response {
+        TranscriptionResponse::Json(transcription) => transcription.text.clone(),
+        TranscriptionResponse::Text(text) => text.clone(),
+        TranscriptionResponse::VerboseJson(transcription) => transcription.text.clone(),
+        TranscriptionResponse::Partial(segment) => segment.text.clone(),
+    }
+}
+
+pub(crate) type EventStream = Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>;
+
+/// Drains `egress` into the `transcript.text.delta`/`transcript.text.done` events described by
+/// [`StreamEvent`]: every [`TranscriptionResponse::Partial`] becomes a `Delta`, and the first
+/// non-`Partial` (i.e. final) response becomes the terminal `Done`, after which the stream ends.
+pub(crate) fn transcription_event_stream(
+    egress: UnboundedReceiver<EndpointResult<TranscriptionResponse>>,
+) -> EventStream {
+    enum State {
+        Active(UnboundedReceiver<EndpointResult<TranscriptionResponse>>),
+        Done,
+    }
+
+    Box::pin(stream::unfold(State::Active(egress), |state| async move {
+        let mut receiver = match state {
+            State::Active(receiver) => receiver,
+            State::Done => return None,
+        };
+
+        let response = match receiver.recv().await {
+            Some(Ok(response)) => response,
+            Some(Err(e)) => {
+                warn!("Handler failed while streaming transcription: {e}");
+                return None;
+            }
+            None => return None,
+        };
+
+        let (event, next_state) = match response {
+            TranscriptionResponse::Partial(segment) => (
+                StreamEvent::Delta(Delta { delta: segment.text }),
+                State::Active(receiver),
+            ),
+            response => (
+                StreamEvent::Done(Done {
+                    text: transcription_text(&response),
+                }),
+                State::Done,
+            ),
+        };
+
+        let event = match Event::default().json_data(event) {
+            Ok(event) => event,
+            Err(e) => {
+                warn!("Failed to serialize transcription stream event: {e}");
+                return None;
+            }
+        };
+
+        Some((Ok(event), next_state))
+    }))
+}
+
+/// [`EncodedTranscription`] for a plain request, or a server-sent-events stream of
+/// [`StreamEvent`]s for a `stream: true` request.
+pub enum TranscribeResponse {
+    Once(EncodedTranscription),
+    Stream(Sse<EventStream>),
+}
+
+impl IntoResponse for TranscribeResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Once(response) => response.into_response(),
+            Self::Stream(sse) => sse.into_response(),
         }
     }
 }
@@ -285,8 +555,27 @@ struct TranscriptionForm {
     /// If set to 0, the model will use log probability to automatically increase the temperature until certain thresholds are hit.
     temperature: Option<f32>,
 
-    /// The format of the output, in one of these options: json, text, verbose_json.
+    /// The format of the output, in one of these options: json, text, srt, verbose_json, or vtt.
     response_format: Option<ResponseFormat>,
+
+    /// The timestamp granularities to populate for this transcription. `response_format` must be
+    /// `verbose_json` for this to take effect. Repeat the field for more than one granularity
+    /// (e.g. `timestamp_granularities[]=segment&timestamp_granularities[]=word`).
+    #[schema(value_type = Vec<String>)]
+    timestamp_granularities: Option<Vec<String>>,
+
+    /// If set, the model response data is streamed as it becomes available, using server-sent
+    /// events and the `transcript.text.delta`/`transcript.text.done` events described by
+    /// [`StreamEvent`].
+    stream: Option<bool>,
+
+    /// Domain vocabulary (product names, jargon) to bias the recognizer towards, as a
+    /// newline-separated list of phrases or a JSON array of strings (e.g. `["hfendpoints", "pyo3"]`).
+    phrases: Option<String>,
+
+    /// How strongly to favor `phrases` over the model's default vocabulary. Ignored if `phrases`
+    /// is not set. Defaults to 1.0.
+    boost: Option<f32>,
 }
 
 #[cfg_attr(feature = "python", pyclass(frozen))]
@@ -299,10 +588,14 @@ pub struct TranscriptionRequest {
     pub prompt: Option<String>,
     pub temperature: f32,
     pub response_format: ResponseFormat,
+    pub timestamp_granularities: Vec<TimestampGranularity>,
+    pub stream: bool,
+    pub speech_context: Option<SpeechContext>,
 }
 
 impl TranscriptionRequest {
     #[instrument(skip_all)]
+    #[allow(clippy::too_many_arguments)]
     fn validate(
         file: Option<Bytes>,
         content_type: String,
@@ -310,6 +603,10 @@ impl TranscriptionRequest {
         prompt: Option<String>,
         temperature: Option<f32>,
         response_format: Option<String>,
+        timestamp_granularities: Vec<String>,
+        stream: Option<bool>,
+        phrases: Option<String>,
+        boost: Option<f32>,
     ) -> HttpResult<Self> {
         let file = match file {
             Some(file) => Ok(file),
@@ -323,14 +620,46 @@ impl TranscriptionRequest {
             "json" => Ok(ResponseFormat::Json),
             "verbose_json" => Ok(ResponseFormat::VerboseJson),
             "text" => Ok(ResponseFormat::Text),
+            "srt" => Ok(ResponseFormat::Srt),
+            "vtt" => Ok(ResponseFormat::Vtt),
             _ => Err(HttpError::Validation(format!(
-                "Unknown response_format: {response_format}. Possible values are: 'json', 'verbose_json', 'text'."
+                "Unknown response_format: {response_format}. Possible values are: 'json', 'verbose_json', 'text', 'srt', 'vtt'."
             ))),
         }?;
 
         let language = language.unwrap_or(String::from("en"));
         let temperature = temperature.unwrap_or(0.0);
 
+        let timestamp_granularities = if timestamp_granularities.is_empty() {
+            vec![TimestampGranularity::Segment]
+        } else {
+            timestamp_granularities
+                .into_iter()
+                .map(|granularity| match granularity.as_str() {
+                    "segment" => Ok(TimestampGranularity::Segment),
+                    "word" => Ok(TimestampGranularity::Word),
+                    _ => Err(HttpError::Validation(format!(
+                        "Unknown timestamp_granularities value: {granularity}. Possible values are: 'segment', 'word'."
+                    ))),
+                })
+                .collect::<HttpResult<Vec<_>>>()?
+        };
+
+        let speech_context = match phrases {
+            Some(phrases) => {
+                let phrases = parse_phrases(&phrases)?;
+                if phrases.is_empty() {
+                    None
+                } else {
+                    Some(SpeechContext {
+                        phrases,
+                        boost: boost.unwrap_or(1.0),
+                    })
+                }
+            }
+            None => None,
+        };
+
         Ok(Self {
             file,
             content_type,
@@ -338,6 +667,9 @@ impl TranscriptionRequest {
             prompt,
             temperature,
             response_format,
+            timestamp_granularities,
+            stream: stream.unwrap_or(false),
+            speech_context,
         })
     }
 
@@ -349,6 +681,10 @@ impl TranscriptionRequest {
         let mut prompt: HttpResult<Option<String>> = Ok(None);
         let mut temperature: HttpResult<Option<f32>> = Ok(None);
         let mut response_format: HttpResult<Option<String>> = Ok(None);
+        let mut timestamp_granularities = Vec::new();
+        let mut stream: HttpResult<Option<bool>> = Ok(None);
+        let mut phrases: Option<String> = None;
+        let mut boost: HttpResult<Option<f32>> = Ok(None);
 
         while let Some(field) = multipart.next_field().await? {
             let name = field.name().unwrap().to_string();
@@ -361,6 +697,16 @@ impl TranscriptionRequest {
                 "prompt" => prompt = Ok(Some(field.text().await?.to_string())),
                 "temperature" => temperature = Ok(Some(f32::from_str(&field.text().await?)?)),
                 "response_format" => response_format = Ok(Some(field.text().await?.to_string())),
+                "timestamp_granularities[]" => {
+                    timestamp_granularities.push(field.text().await?.to_string())
+                }
+                "stream" => {
+                    stream = Ok(Some(field.text().await?.parse().map_err(|_| {
+                        HttpError::Validation(String::from("stream must be 'true' or 'false'"))
+                    })?))
+                }
+                "phrases" => phrases = Some(field.text().await?.to_string()),
+                "boost" => boost = Ok(Some(f32::from_str(&field.text().await?)?)),
                 _ => return Err(HttpError::Validation(format!("Unknown field: {name}"))),
             }
         }
@@ -372,6 +718,10 @@ impl TranscriptionRequest {
             prompt?,
             temperature?,
             response_format?,
+            timestamp_granularities,
+            stream?,
+            phrases,
+            boost?,
         )
     }
 }
@@ -392,20 +742,147 @@ pub async fn transcribe(
     State(state): State<EndpointContext<TranscriptionRequestWithContext, TranscriptionResponse>>,
     request_id: TypedHeader<RequestId>,
     multipart: Multipart,
-) -> HttpResult<TranscriptionResponse> {
+) -> HttpResult<TranscribeResponse> {
     // Decode request
     let request = TranscriptionRequest::try_from_multipart(multipart).await?;
+    let response_format = request.response_format;
+    let stream = request.stream;
 
     // Create request context
     let ctx = Context::new(request_id.0);
 
     // Ask for the inference thread to handle it and wait for answers
-    let mut egress = state.schedule((request, ctx));
-    if let Some(response) = egress.recv().await {
-        Ok(response?)
+    let mut egress = state.schedule((request, ctx))?;
+
+    if stream {
+        return Ok(TranscribeResponse::Stream(
+            Sse::new(transcription_event_stream(egress)).keep_alive(KeepAlive::default()),
+        ));
+    }
+
+    let response = if let Some(response) = egress.recv().await {
+        response?
     } else {
-        Err(HttpError::NoResponse)
+        return Err(HttpError::NoResponse);
+    };
+
+    Ok(TranscribeResponse::Once(match response_format {
+        ResponseFormat::Srt | ResponseFormat::Vtt => {
+            let segments = match &response {
+                TranscriptionResponse::VerboseJson(transcription) => {
+                    transcription.segments.clone().unwrap_or_default()
+                }
+                _ => Vec::new(),
+            };
+
+            if matches!(response_format, ResponseFormat::Srt) {
+                EncodedTranscription::Subtitle(segments_to_srt(&segments), "application/x-subrip")
+            } else {
+                EncodedTranscription::Subtitle(segments_to_vtt(&segments), "text/vtt")
+            }
+        }
+        _ => EncodedTranscription::Response(response),
+    }))
+}
+
+/// Forwards `response` to `socket` as a JSON text frame, returning `false` once the connection
+/// should be torn down (either the client went away, or `response` was the final, non-`Partial`
+/// message a streaming handler produces).
+#[instrument(skip(socket, response))]
+async fn send_stream_response(socket: &mut WebSocket, response: &TranscriptionResponse) -> bool {
+    let is_partial = matches!(response, TranscriptionResponse::Partial(_));
+
+    let frame = match serde_json::to_string(response) {
+        Ok(frame) => frame,
+        Err(e) => {
+            warn!("Failed to serialize transcription response: {e}");
+            return false;
+        }
+    };
+
+    if let Err(e) = socket.send(Message::Text(frame.into())).await {
+        warn!("Failed to send transcription frame to client: {e}");
+        return false;
+    }
+
+    is_partial
+}
+
+/// Handles a single `transcribe_stream` WebSocket connection: the client sends the audio to
+/// transcribe as one binary frame, and receives one JSON text frame per `Segment` as it is
+/// decoded, followed by a final frame carrying the complete `Transcription`.
+#[instrument(skip(socket, state))]
+async fn handle_transcription_stream(
+    mut socket: WebSocket,
+    state: EndpointContext<TranscriptionRequestWithContext, TranscriptionResponse>,
+    ctx: Context,
+) {
+    let file = loop {
+        match socket.recv().await {
+            Some(Ok(Message::Binary(data))) => break data,
+            Some(Ok(Message::Close(_))) | None => return,
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => {
+                warn!("Error while reading audio from client: {e}");
+                return;
+            }
+        }
+    };
+
+    let request = TranscriptionRequest {
+        file: file.into(),
+        content_type: String::from("application/octet-stream"),
+        language: String::from("en"),
+        prompt: None,
+        temperature: 0.0,
+        response_format: ResponseFormat::VerboseJson,
+        timestamp_granularities: vec![TimestampGranularity::Segment],
+        stream: false,
+        speech_context: None,
+    };
+
+    let mut egress = match state.schedule((request, ctx)) {
+        Ok(egress) => egress,
+        Err(e) => {
+            warn!("Failed to schedule transcription request: {e}");
+            return;
+        }
+    };
+    while let Some(response) = egress.recv().await {
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                warn!("Handler failed while streaming transcription: {e}");
+                break;
+            }
+        };
+
+        if !send_stream_response(&mut socket, &response).await {
+            break;
+        }
     }
+
+    debug!("Transcription stream closed");
+}
+
+/// Upgrades the connection to a WebSocket, next to the plain HTTP `transcribe` route, for
+/// real-time captioning clients that want to render text as speech arrives.
+#[utoipa::path(
+    get,
+    path = "/audio/transcriptions/stream",
+    tag = AUDIO_TAG,
+    responses(
+        (status = OK, description = "Transcribes audio into the input language, streaming one Segment at a time."),
+    )
+)]
+#[instrument(skip(state, ws))]
+pub async fn transcribe_stream(
+    State(state): State<EndpointContext<TranscriptionRequestWithContext, TranscriptionResponse>>,
+    request_id: TypedHeader<RequestId>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let ctx = Context::new(request_id.0);
+    ws.on_upgrade(move |socket| handle_transcription_stream(socket, state, ctx))
 }
 
 /// Helper factory to build
@@ -422,16 +899,18 @@ impl From<TranscriptionRouter> for OpenApiRouter {
     fn from(value: TranscriptionRouter) -> Self {
         OpenApiRouter::new()
             .routes(routes!(transcribe))
+            .routes(routes!(transcribe_stream))
             .with_state(EndpointContext::new(value.0))
             .layer(DefaultBodyLimit::max(200 * 1024 * 1024)) // 200Mb as OpenAI
+            .layer(Timeout::try_from_env().unwrap_or_default().layer_for("audio"))
     }
 }
 
 #[cfg(feature = "python")]
 pub(crate) mod python {
     use crate::audio::transcription::{
-        ResponseFormat, Segment, Transcription, TranscriptionRequest, TranscriptionResponse,
-        VerboseTranscription,
+        ResponseFormat, Segment, SpeechContext, TimestampGranularity, Transcription,
+        TranscriptionRequest, TranscriptionResponse, VerboseTranscription, Word,
     };
     use hfendpoints_binding_python::fill_view_from_readonly_data;
     use pyo3::ffi::Py_buffer;
@@ -482,6 +961,27 @@ pub(crate) mod python {
         }
     }
 
+    #[pymethods]
+    impl Word {
+        #[new]
+        pub fn new(word: String, start: f32, end: f32) -> Self {
+            Self { word, start, end }
+        }
+    }
+
+    #[pymethods]
+    impl SpeechContext {
+        #[getter]
+        pub fn phrases(&self) -> Vec<String> {
+            self.phrases.clone()
+        }
+
+        #[getter]
+        pub fn boost(&self) -> f32 {
+            self.boost
+        }
+    }
+
     #[pymethods]
     impl Transcription {
         #[new]
@@ -493,12 +993,20 @@ pub(crate) mod python {
     #[pymethods]
     impl VerboseTranscription {
         #[new]
-        pub fn new(text: String, duration: f32, language: String, segments: Vec<Segment>) -> Self {
+        #[pyo3(signature = (text, duration, language, segments=None, words=None))]
+        pub fn new(
+            text: String,
+            duration: f32,
+            language: String,
+            segments: Option<Vec<Segment>>,
+            words: Option<Vec<Word>>,
+        ) -> Self {
             Self {
                 text,
                 duration,
                 language,
                 segments,
+                words,
             }
         }
     }
@@ -539,12 +1047,27 @@ pub(crate) mod python {
             self.temperature
         }
 
+        #[getter]
+        pub fn timestamp_granularities(&self) -> Vec<TimestampGranularity> {
+            self.timestamp_granularities.clone()
+        }
+
+        #[getter]
+        pub fn speech_context(&self) -> Option<SpeechContext> {
+            self.speech_context.clone()
+        }
+
+        /// The kind of `TranscriptionResponse` the handler should build. `Srt`/`Vtt` are rendered
+        /// from segment timestamps by the `transcribe` route itself, so they ask the handler for
+        /// the same `VerboseJson` shape as a `verbose_json` request.
         #[getter]
         pub fn response_kind(&self) -> PyResult<TranscriptionResponseKind> {
             match self.response_format {
                 ResponseFormat::Json => Ok(TranscriptionResponseKind::Json),
                 ResponseFormat::Text => Ok(TranscriptionResponseKind::Text),
-                ResponseFormat::VerboseJson => Ok(TranscriptionResponseKind::VerboseJson),
+                ResponseFormat::VerboseJson | ResponseFormat::Srt | ResponseFormat::Vtt => {
+                    Ok(TranscriptionResponseKind::VerboseJson)
+                }
             }
         }
     }
@@ -570,7 +1093,26 @@ pub(crate) mod python {
 
 #[cfg(test)]
 mod tests {
-    use crate::audio::transcription::{Delta, Done, Segment, StreamEvent};
+    use crate::audio::transcription::{
+        parse_phrases, segments_to_srt, segments_to_vtt, Delta, Done, Segment, StreamEvent, Word,
+    };
+
+    #[test]
+    fn parse_phrases_reads_json_array() {
+        let phrases = parse_phrases(r#"["hfendpoints", "pyo3"]"#).unwrap();
+        assert_eq!(phrases, vec![String::from("hfendpoints"), String::from("pyo3")]);
+    }
+
+    #[test]
+    fn parse_phrases_reads_newline_separated_list() {
+        let phrases = parse_phrases("hfendpoints\n\npyo3\n").unwrap();
+        assert_eq!(phrases, vec![String::from("hfendpoints"), String::from("pyo3")]);
+    }
+
+    #[test]
+    fn parse_phrases_rejects_malformed_json_array() {
+        assert!(parse_phrases("[\"unterminated").is_err());
+    }
 
     #[test]
     fn serialize_stream_event_delta() {
@@ -655,4 +1197,66 @@ mod tests {
             panic!("Failed to create segment");
         }
     }
+
+    #[test]
+    fn word_builder_all_fields_set() {
+        if let Ok(word) = Word::builder()
+            .word(String::from("Hello"))
+            .start(2.2)
+            .end(3.8)
+            .build()
+        {
+            assert_eq!(word.word, String::from("Hello"));
+            assert_eq!(word.start, 2.2);
+            assert_eq!(word.end, 3.8);
+        } else {
+            panic!("Failed to create word");
+        }
+    }
+
+    #[test]
+    fn word_builder_missing_field_fails() {
+        assert!(Word::builder().start(2.2).end(3.8).build().is_err());
+    }
+
+    fn sample_segments() -> Vec<Segment> {
+        vec![
+            Segment::builder()
+                .id(0)
+                .start(0.0)
+                .end(1.5)
+                .temperature(0.0)
+                .text(String::from("Hello"))
+                .tokens(vec![1])
+                .build()
+                .unwrap(),
+            Segment::builder()
+                .id(1)
+                .start(1.5)
+                .end(3.25)
+                .temperature(0.0)
+                .text(String::from("world"))
+                .tokens(vec![2])
+                .build()
+                .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn segments_to_srt_renders_numbered_cues() {
+        let srt = segments_to_srt(&sample_segments());
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,500\nHello\n\n2\n00:00:01,500 --> 00:00:03,250\nworld\n"
+        );
+    }
+
+    #[test]
+    fn segments_to_vtt_renders_header_and_cues() {
+        let vtt = segments_to_vtt(&sample_segments());
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n1\n00:00:00.000 --> 00:00:01.500\nHello\n\n2\n00:00:01.500 --> 00:00:03.250\nworld\n"
+        );
+    }
 }
@@ -0,0 +1,300 @@
+use crate::audio::transcription::{
+    segments_to_srt, segments_to_vtt, transcription_event_stream, EncodedTranscription,
+    ResponseFormat, TimestampGranularity, TranscribeResponse, TranscriptionResponse,
+};
+use crate::audio::AUDIO_TAG;
+use crate::context::Context;
+use crate::environ::Timeout;
+use crate::headers::RequestId;
+use crate::{HttpError, HttpResult, RequestWithContext};
+use axum::body::Bytes;
+use axum::extract::{DefaultBodyLimit, Multipart, State};
+use axum::response::sse::{KeepAlive, Sse};
+use axum_extra::TypedHeader;
+use hfendpoints_core::environ::TryFromEnv;
+use hfendpoints_core::{EndpointContext, EndpointResult};
+use std::str::FromStr;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Translates audio into English, regardless of the spoken language.
+#[derive(ToSchema)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct TranslationForm {
+    /// The audio file object (not file name) to translate, in one of these formats: flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm.
+    #[schema(format = Binary)]
+    file: String,
+
+    /// Not used, here for compatibility purpose with OpenAI Platform
+    model: Option<String>,
+
+    /// An optional text to guide the model's style or continue a previous audio segment.
+    /// The prompt should be in English.
+    prompt: Option<String>,
+
+    /// The sampling temperature, between 0 and 1.
+    /// Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
+    /// If set to 0, the model will use log probability to automatically increase the temperature until certain thresholds are hit.
+    temperature: Option<f32>,
+
+    /// The format of the output, in one of these options: json, text, srt, verbose_json, or vtt.
+    response_format: Option<ResponseFormat>,
+
+    /// The timestamp granularities to populate for this translation. `response_format` must be
+    /// `verbose_json` for this to take effect. Repeat the field for more than one granularity
+    /// (e.g. `timestamp_granularities[]=segment&timestamp_granularities[]=word`).
+    #[schema(value_type = Vec<String>)]
+    timestamp_granularities: Option<Vec<String>>,
+
+    /// If set, the model response data is streamed as it becomes available, using server-sent
+    /// events and the `transcript.text.delta`/`transcript.text.done` events.
+    stream: Option<bool>,
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone)]
+pub struct TranslationRequest {
+    pub file: Bytes,
+    pub content_type: String,
+    pub prompt: Option<String>,
+    pub temperature: f32,
+    pub response_format: ResponseFormat,
+    pub timestamp_granularities: Vec<TimestampGranularity>,
+    pub stream: bool,
+}
+
+impl TranslationRequest {
+    #[instrument(skip_all)]
+    fn validate(
+        file: Option<Bytes>,
+        content_type: String,
+        prompt: Option<String>,
+        temperature: Option<f32>,
+        response_format: Option<String>,
+        timestamp_granularities: Vec<String>,
+        stream: Option<bool>,
+    ) -> HttpResult<Self> {
+        let file = match file {
+            Some(file) => Ok(file),
+            None => Err(HttpError::Validation(
+                "Required parameter 'file' was not provided".to_string(),
+            )),
+        }?;
+
+        let response_format = response_format.unwrap_or(String::from("json"));
+        let response_format = match response_format.as_str() {
+            "json" => Ok(ResponseFormat::Json),
+            "verbose_json" => Ok(ResponseFormat::VerboseJson),
+            "text" => Ok(ResponseFormat::Text),
+            "srt" => Ok(ResponseFormat::Srt),
+            "vtt" => Ok(ResponseFormat::Vtt),
+            _ => Err(HttpError::Validation(format!(
+                "Unknown response_format: {response_format}. Possible values are: 'json', 'verbose_json', 'text', 'srt', 'vtt'."
+            ))),
+        }?;
+
+        let temperature = temperature.unwrap_or(0.0);
+
+        let timestamp_granularities = if timestamp_granularities.is_empty() {
+            vec![TimestampGranularity::Segment]
+        } else {
+            timestamp_granularities
+                .into_iter()
+                .map(|granularity| match granularity.as_str() {
+                    "segment" => Ok(TimestampGranularity::Segment),
+                    "word" => Ok(TimestampGranularity::Word),
+                    _ => Err(HttpError::Validation(format!(
+                        "Unknown timestamp_granularities value: {granularity}. Possible values are: 'segment', 'word'."
+                    ))),
+                })
+                .collect::<HttpResult<Vec<_>>>()?
+        };
+
+        Ok(Self {
+            file,
+            content_type,
+            prompt,
+            temperature,
+            response_format,
+            timestamp_granularities,
+            stream: stream.unwrap_or(false),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn try_from_multipart(mut multipart: Multipart) -> HttpResult<Self> {
+        let mut file: HttpResult<Option<Bytes>> = Ok(None);
+        let mut content_type: Option<String> = None;
+        let mut prompt: HttpResult<Option<String>> = Ok(None);
+        let mut temperature: HttpResult<Option<f32>> = Ok(None);
+        let mut response_format: HttpResult<Option<String>> = Ok(None);
+        let mut timestamp_granularities = Vec::new();
+        let mut stream: HttpResult<Option<bool>> = Ok(None);
+
+        while let Some(field) = multipart.next_field().await? {
+            let name = field.name().unwrap().to_string();
+            match name.as_str() {
+                "file" => {
+                    content_type = Some(field.content_type().unwrap_or("unknown").to_string());
+                    file = Ok(Some(field.bytes().await?));
+                }
+                "prompt" => prompt = Ok(Some(field.text().await?.to_string())),
+                "temperature" => temperature = Ok(Some(f32::from_str(&field.text().await?)?)),
+                "response_format" => response_format = Ok(Some(field.text().await?.to_string())),
+                "timestamp_granularities[]" => {
+                    timestamp_granularities.push(field.text().await?.to_string())
+                }
+                "stream" => {
+                    stream = Ok(Some(field.text().await?.parse().map_err(|_| {
+                        HttpError::Validation(String::from("stream must be 'true' or 'false'"))
+                    })?))
+                }
+                _ => return Err(HttpError::Validation(format!("Unknown field: {name}"))),
+            }
+        }
+
+        Self::validate(
+            file?,
+            content_type.unwrap(),
+            prompt?,
+            temperature?,
+            response_format?,
+            timestamp_granularities,
+            stream?,
+        )
+    }
+}
+
+type TranslationRequestWithContext = RequestWithContext<TranslationRequest>;
+
+#[utoipa::path(
+    post,
+    path = "/audio/translations",
+    tag = AUDIO_TAG,
+    request_body(content = TranslationForm, content_type = "multipart/form-data"),
+    responses(
+        (status = OK, description = "Translates audio into English.", body = TranscriptionResponse),
+    )
+)]
+#[instrument(skip(state, multipart))]
+pub async fn translate(
+    State(state): State<EndpointContext<TranslationRequestWithContext, TranscriptionResponse>>,
+    request_id: TypedHeader<RequestId>,
+    multipart: Multipart,
+) -> HttpResult<TranscribeResponse> {
+    // Decode request
+    let request = TranslationRequest::try_from_multipart(multipart).await?;
+    let response_format = request.response_format;
+    let stream = request.stream;
+
+    // Create request context
+    let ctx = Context::new(request_id.0);
+
+    // Ask for the inference thread to handle it and wait for answers
+    let mut egress = state.schedule((request, ctx))?;
+
+    if stream {
+        return Ok(TranscribeResponse::Stream(
+            Sse::new(transcription_event_stream(egress)).keep_alive(KeepAlive::default()),
+        ));
+    }
+
+    let response = if let Some(response) = egress.recv().await {
+        response?
+    } else {
+        return Err(HttpError::NoResponse);
+    };
+
+    Ok(TranscribeResponse::Once(match response_format {
+        ResponseFormat::Srt | ResponseFormat::Vtt => {
+            let segments = match &response {
+                TranscriptionResponse::VerboseJson(transcription) => {
+                    transcription.segments.clone().unwrap_or_default()
+                }
+                _ => Vec::new(),
+            };
+
+            if matches!(response_format, ResponseFormat::Srt) {
+                EncodedTranscription::Subtitle(segments_to_srt(&segments), "application/x-subrip")
+            } else {
+                EncodedTranscription::Subtitle(segments_to_vtt(&segments), "text/vtt")
+            }
+        }
+        _ => EncodedTranscription::Response(response),
+    }))
+}
+
+/// Helper factory to build
+/// [OpenAi Platform compatible Translation endpoint](https://platform.openai.com/docs/api-reference/audio/createTranslation)
+#[derive(Clone)]
+pub struct TranslationRouter(
+    pub  UnboundedSender<(
+        TranslationRequestWithContext,
+        UnboundedSender<EndpointResult<TranscriptionResponse>>,
+    )>,
+);
+
+impl From<TranslationRouter> for OpenApiRouter {
+    fn from(value: TranslationRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(translate))
+            .with_state(EndpointContext::new(value.0))
+            .layer(DefaultBodyLimit::max(200 * 1024 * 1024)) // 200Mb as OpenAI
+            .layer(Timeout::try_from_env().unwrap_or_default().layer_for("audio"))
+    }
+}
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::audio::transcription::TimestampGranularity;
+    use crate::audio::translation::TranslationRequest;
+    use hfendpoints_binding_python::fill_view_from_readonly_data;
+    use pyo3::ffi::Py_buffer;
+    use pyo3::prelude::*;
+    use std::ffi::CString;
+    use tracing::{debug, instrument};
+
+    #[pymethods]
+    impl TranslationRequest {
+        #[instrument(skip(slf, buffer))]
+        pub unsafe fn __getbuffer__(
+            slf: Bound<'_, Self>,
+            buffer: *mut Py_buffer,
+            flags: i32,
+        ) -> PyResult<()> {
+            debug!("Acquiring a memoryview over audio data (flags={})", flags);
+            unsafe {
+                fill_view_from_readonly_data(buffer, flags, &slf.borrow().file, slf.into_any())
+            }
+        }
+
+        #[instrument(skip_all)]
+        pub unsafe fn __releasebuffer__(&self, buffer: *mut Py_buffer) {
+            debug!("Releasing Python memoryview");
+            // Release memory held by the format string
+            drop(unsafe { CString::from_raw((*buffer).format) });
+        }
+
+        #[getter]
+        pub fn prompt(&self) -> &Option<String> {
+            &self.prompt
+        }
+
+        #[getter]
+        pub fn temperature(&self) -> f32 {
+            self.temperature
+        }
+
+        #[getter]
+        pub fn timestamp_granularities(&self) -> Vec<TimestampGranularity> {
+            self.timestamp_granularities.clone()
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::debug;
+
+/// Handle to gracefully stop a running `serve_http` endpoint.
+///
+/// Calling [`Driver::stop`] tells the `axum::serve` accept loop behind `serve_http` to stop taking
+/// new connections and return once in-flight requests have drained, instead of running until the
+/// process is killed. This is what lets an embedding application, or a test harness, start and
+/// stop an endpoint repeatedly within the same process.
+#[derive(Clone)]
+pub struct Driver {
+    shutdown: watch::Sender<bool>,
+}
+
+impl Driver {
+    pub fn new() -> Self {
+        let (shutdown, _) = watch::channel(false);
+        Self { shutdown }
+    }
+
+    /// Begin a graceful shutdown of whichever `serve_http` call this `Driver` was passed to.
+    pub fn stop(&self) {
+        let _ = self.shutdown.send(true);
+    }
+
+    /// Future that resolves once `stop()` has been called; handed to
+    /// `axum::serve(...).with_graceful_shutdown(...)` by `serve_http`, and to
+    /// `Server::serve_with_incoming_shutdown(...)` by `hfendpoints_grpc::serve_grpc`.
+    pub fn shutdown_signal(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.shutdown.subscribe();
+        async move {
+            // `changed()` only errors once every sender is dropped, meaning `stop()` can no
+            // longer ever be called -- run forever rather than shut down immediately.
+            while receiver.changed().await.is_ok() {
+                if *receiver.borrow() {
+                    return;
+                }
+            }
+            std::future::pending().await
+        }
+    }
+
+    /// Like [`Driver::shutdown_signal`], but once `stop()` is called, waits out `drain` before
+    /// resolving -- keeping the listener accepting connections for that long so requests already
+    /// in flight (e.g. a long audio transcription) get a chance to finish instead of being cut
+    /// off the instant a shutdown is requested.
+    pub fn shutdown_signal_with_drain(
+        &self,
+        drain: Duration,
+    ) -> impl Future<Output = ()> + Send + 'static {
+        let signal = self.shutdown_signal();
+        async move {
+            signal.await;
+            debug!("[Driver] Shutdown requested, draining for {drain:?} before stopping");
+            tokio::time::sleep(drain).await;
+        }
+    }
+}
+
+impl Default for Driver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
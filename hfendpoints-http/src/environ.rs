@@ -1,4 +1,5 @@
 use hfendpoints_core::environ::{EnvironmentError, TryFromEnv};
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 use tower_http::timeout::TimeoutLayer;
@@ -25,6 +26,7 @@ use tracing::debug;
 ///
 /// let timeout = Timeout {
 ///     duration: Duration::new(5, 0), // 5 seconds
+///     ..Default::default()
 /// };
 ///
 /// // Use the timeout in your application...
@@ -32,6 +34,52 @@ use tracing::debug;
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct Timeout {
     pub duration: Duration,
+
+    /// Per route-group overrides (e.g. `"audio"` -> `600s`), keyed by the uppercased tag passed
+    /// to [`Timeout::layer_for`]. Populated from `HFENDPOINTS_TIMEOUT_<TAG>_SEC` environment
+    /// variables; a tag with no matching variable falls back to `duration`.
+    overrides: HashMap<String, Duration>,
+
+    /// How long a graceful shutdown waits for in-flight requests to complete before the listener
+    /// stops accepting new connections, so audio/embedding inference already underway isn't cut
+    /// short the instant a shutdown is requested.
+    pub graceful_drain: Duration,
+}
+
+impl Default for Timeout {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(120),
+            overrides: HashMap::new(),
+            graceful_drain: Duration::from_secs(30),
+        }
+    }
+}
+
+impl Timeout {
+    /// Prefix of a per-tag override environment variable, e.g. `HFENDPOINTS_TIMEOUT_AUDIO_SEC`.
+    const OVERRIDE_ENV_PREFIX: &'static str = "HFENDPOINTS_TIMEOUT_";
+
+    /// Suffix of a per-tag override environment variable, e.g. `HFENDPOINTS_TIMEOUT_AUDIO_SEC`.
+    const OVERRIDE_ENV_SUFFIX: &'static str = "_SEC";
+
+    /// The environment variable controlling how long a graceful shutdown waits for in-flight
+    /// requests to drain before the listener stops accepting connections.
+    const GRACEFUL_DRAIN_ENV_VAR_NAME: &'static str = "HFENDPOINTS_GRACEFUL_DRAIN_SEC";
+
+    /// The [`TimeoutLayer`] that should guard routes tagged `tag` (e.g. `"audio"`,
+    /// `"embeddings"`, `"status"`), using the `HFENDPOINTS_TIMEOUT_<TAG>_SEC` override when one
+    /// is set, and falling back to the process-wide default otherwise.
+    pub fn layer_for(&self, tag: &str) -> TimeoutLayer {
+        let duration = self
+            .overrides
+            .get(&tag.to_ascii_uppercase())
+            .copied()
+            .unwrap_or(self.duration);
+
+        debug!("[Environ] Timeout for '{tag}' routes set to {duration:?}");
+        TimeoutLayer::new(duration)
+    }
 }
 
 impl TryFromEnv for Timeout {
@@ -109,18 +157,51 @@ impl TryFromEnv for Timeout {
     where
         Self: Sized,
     {
-        match u64::from_str(&std::env::var(Self::ENV_VAR_NAME).unwrap_or(String::from("120"))) {
+        let duration = match u64::from_str(&std::env::var(Self::ENV_VAR_NAME).unwrap_or(String::from("120"))) {
             Ok(timeout) => {
                 debug!("[Environ] Timeout set to {} seconds", timeout);
-                Ok(Self {
-                    duration: Duration::from_secs(timeout),
-                })
+                Duration::from_secs(timeout)
+            }
+            Err(err) => {
+                return Err(EnvironmentError::InvalidEnvVar(
+                    Self::ENV_VAR_NAME.into(),
+                    err.to_string(),
+                ))
             }
-            Err(err) => Err(EnvironmentError::InvalidEnvVar(
-                Self::ENV_VAR_NAME.into(),
-                err.to_string(),
-            )),
+        };
+
+        let mut overrides = HashMap::new();
+        for (name, value) in std::env::vars() {
+            let Some(tag) = name
+                .strip_prefix(Self::OVERRIDE_ENV_PREFIX)
+                .and_then(|rest| rest.strip_suffix(Self::OVERRIDE_ENV_SUFFIX))
+            else {
+                continue;
+            };
+
+            let seconds = u64::from_str(&value)
+                .map_err(|err| EnvironmentError::InvalidEnvVar(name.clone(), err.to_string()))?;
+
+            debug!("[Environ] Timeout override for '{tag}' routes set to {seconds} seconds");
+            overrides.insert(tag.to_string(), Duration::from_secs(seconds));
         }
+
+        let graceful_drain = match std::env::var(Self::GRACEFUL_DRAIN_ENV_VAR_NAME) {
+            Ok(value) => u64::from_str(&value).map_err(|err| {
+                EnvironmentError::InvalidEnvVar(
+                    Self::GRACEFUL_DRAIN_ENV_VAR_NAME.into(),
+                    err.to_string(),
+                )
+            })?,
+            Err(_) => 30,
+        };
+        debug!("[Environ] Graceful drain set to {} seconds", graceful_drain);
+
+        Ok(Self {
+            duration,
+            overrides,
+            graceful_drain: Duration::from_secs(graceful_drain),
+        })
     }
 }
 
@@ -153,4 +234,43 @@ mod tests {
         let result = Timeout::try_from_env();
         assert!(matches!(result, Err(EnvironmentError::InvalidEnvVar(_, _))));
     }
+
+    #[test]
+    fn test_timeout_per_tag_override_falls_back_to_default() {
+        unsafe {
+            env::set_var(Timeout::ENV_VAR_NAME, "5");
+            env::remove_var("HFENDPOINTS_TIMEOUT_AUDIO_SEC");
+        }
+        let timeout = Timeout::try_from_env().unwrap();
+        assert_eq!(timeout.overrides.get("AUDIO"), None);
+        unsafe {
+            env::remove_var(Timeout::ENV_VAR_NAME);
+        }
+    }
+
+    #[test]
+    fn test_timeout_per_tag_override_is_parsed() {
+        unsafe {
+            env::set_var(Timeout::ENV_VAR_NAME, "5");
+            env::set_var("HFENDPOINTS_TIMEOUT_AUDIO_SEC", "600");
+        }
+        let timeout = Timeout::try_from_env().unwrap();
+        assert_eq!(
+            timeout.overrides.get("AUDIO"),
+            Some(&Duration::from_secs(600))
+        );
+        unsafe {
+            env::remove_var(Timeout::ENV_VAR_NAME);
+            env::remove_var("HFENDPOINTS_TIMEOUT_AUDIO_SEC");
+        }
+    }
+
+    #[test]
+    fn test_graceful_drain_defaults_when_unset() {
+        unsafe {
+            env::remove_var(Timeout::GRACEFUL_DRAIN_ENV_VAR_NAME);
+        }
+        let timeout = Timeout::try_from_env().unwrap();
+        assert_eq!(timeout.graceful_drain, Duration::from_secs(30));
+    }
 }
@@ -2,6 +2,8 @@ use axum::extract::multipart::MultipartError;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use hfendpoints_core::Error as EndpointError;
+use hfendpoints_core::HandlerError;
+use hfendpoints_core::MiddlewareError;
 use std::num::ParseFloatError;
 use thiserror::Error;
 use tokio::io::Error as TokioIoError;
@@ -23,6 +25,9 @@ pub enum HttpError {
 
     #[error("No response was returned by the inference engine")]
     NoResponse,
+
+    #[error("Not acceptable: client asked for '{0}', which this endpoint cannot produce")]
+    NotAcceptable(String),
 }
 
 impl From<ParseFloatError> for HttpError {
@@ -35,6 +40,21 @@ impl From<ParseFloatError> for HttpError {
 impl IntoResponse for HttpError {
     fn into_response(self) -> Response {
         let (status, body) = match self {
+            // A middleware's `MiddlewareException` is a deliberate rejection, not a server
+            // fault: it behaves like `Validation` (400 by default), unless the middleware named
+            // its own status code.
+            Self::Endpoint(EndpointError::Middleware(MiddlewareError::Rejected(msg, status))) => (
+                status
+                    .and_then(|code| StatusCode::from_u16(code).ok())
+                    .unwrap_or(StatusCode::BAD_REQUEST),
+                msg.to_string(),
+            ),
+            // `wait_for_requests` rejected the request because its `max_queue` bound of
+            // in-flight requests was exceeded: tell the client to back off and retry.
+            Self::Endpoint(EndpointError::Handler(HandlerError::Overloaded)) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                HandlerError::Overloaded.to_string(),
+            ),
             Self::Endpoint(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
             Self::Io(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
             Self::Validation(e) => (StatusCode::BAD_REQUEST, e),
@@ -43,6 +63,7 @@ impl IntoResponse for HttpError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 String::from("No response returned by the inference engine"),
             ),
+            Self::NotAcceptable(e) => (StatusCode::NOT_ACCEPTABLE, e),
         };
 
         (status, body).into_response()
@@ -0,0 +1,31 @@
+use crate::HttpError;
+use axum::extract::{FromRequest, Request};
+use axum::Json;
+use hfendpoints_core::Validate;
+use serde::de::DeserializeOwned;
+
+/// Like [`axum::Json`], but additionally runs the deserialized value's [`Validate`]
+/// implementation before handing it to the route, so a handler never sees a request that
+/// fails its own declared constraints. Rejections collapse to `HttpError::Validation`,
+/// carrying every failing field rather than just the first one.
+pub struct ValidatedJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = HttpError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|e| HttpError::Validation(e.to_string()))?;
+
+        value
+            .validate()
+            .map_err(|errors| HttpError::Validation(errors.to_string()))?;
+
+        Ok(Self(value))
+    }
+}
@@ -0,0 +1,149 @@
+//! Word-level forced alignment: given an acoustic model's per-frame token emission
+//! log-probabilities, find the frame span each transcript word was spoken in.
+
+/// Forced-aligns `tokens` (one entry per transcript character) against `frame_count` acoustic
+/// frames via Viterbi decoding over a trellis of token states interleaved with blank states --
+/// the same shape CTC forced alignment (torchaudio's `forced_align`, Gentle, MFA) uses. At each
+/// frame the best path either stays in its current state or advances to the next one, and
+/// `emission(frame, token)` supplies that transition's log-probability (`token` is `None` for a
+/// blank state). Backtracking the best path through the trellis yields one contiguous frame span
+/// per transcript token.
+///
+/// Returns `None` when there are fewer frames than trellis states, since no monotonic path can
+/// then visit every state at least once.
+fn align_tokens(
+    tokens: &[char],
+    frame_count: usize,
+    emission: impl Fn(usize, Option<char>) -> f32,
+) -> Option<Vec<(usize, usize)>> {
+    if tokens.is_empty() || frame_count == 0 {
+        return None;
+    }
+
+    // Interleave a blank state around and between every token: blank, t0, blank, t1, blank, ...
+    let states: Vec<Option<char>> = std::iter::once(None)
+        .chain(tokens.iter().flat_map(|&token| [Some(token), None]))
+        .collect();
+    let num_states = states.len();
+
+    if frame_count < num_states {
+        return None;
+    }
+
+    const NEG_INF: f32 = f32::NEG_INFINITY;
+
+    // `dp[state]` is the best cumulative log-prob of a path ending in `state` at the frame just
+    // processed; `advanced_into[frame][state]` records whether that best path reached `state` at
+    // `frame` by advancing from `state - 1` (true) or by staying in `state` (false).
+    let mut dp = vec![NEG_INF; num_states];
+    dp[0] = emission(0, states[0]);
+
+    let mut advanced_into = vec![vec![false; num_states]; frame_count];
+
+    for frame in 1..frame_count {
+        let mut next = vec![NEG_INF; num_states];
+        for state in 0..num_states {
+            let stay = dp[state];
+            let advance = if state > 0 { dp[state - 1] } else { NEG_INF };
+            let (best, came_from_advance) = if advance > stay {
+                (advance, true)
+            } else {
+                (stay, false)
+            };
+
+            if best.is_finite() {
+                next[state] = best + emission(frame, states[state]);
+                advanced_into[frame][state] = came_from_advance;
+            }
+        }
+        dp = next;
+    }
+
+    // The alignment must end either in the last token's state or its trailing blank.
+    let final_state = if num_states > 1 && dp[num_states - 2] > dp[num_states - 1] {
+        num_states - 2
+    } else {
+        num_states - 1
+    };
+
+    if !dp[final_state].is_finite() {
+        return None;
+    }
+
+    let mut state_at_frame = vec![0usize; frame_count];
+    state_at_frame[frame_count - 1] = final_state;
+    for frame in (1..frame_count).rev() {
+        state_at_frame[frame - 1] = if advanced_into[frame][state_at_frame[frame]] {
+            state_at_frame[frame] - 1
+        } else {
+            state_at_frame[frame]
+        };
+    }
+
+    // Collapse the per-frame state sequence into one contiguous span per *token* state (odd
+    // indices in `states`), dropping the interleaved blanks.
+    let mut spans: Vec<Option<(usize, usize)>> = vec![None; tokens.len()];
+    let mut run_start = 0;
+    for frame in 1..=frame_count {
+        if frame == frame_count || state_at_frame[frame] != state_at_frame[run_start] {
+            let state = state_at_frame[run_start];
+            if state % 2 == 1 {
+                spans[state / 2] = Some((run_start, frame - 1));
+            }
+            run_start = frame;
+        }
+    }
+
+    spans.into_iter().collect()
+}
+
+/// Forced-aligns `transcript` against `frame_count` acoustic frames of `emission` log-probabilities,
+/// then merges the resulting per-character spans into per-word `(word, start_seconds,
+/// end_seconds)` triples at whitespace boundaries, converting frame indices to seconds via
+/// `frame_hop`.
+///
+/// Returns `None` when the alignment itself is impossible (see [`align_tokens`]), in which case
+/// callers should fall back to whatever coarser timestamps they already have.
+pub(crate) fn align_words(
+    transcript: &str,
+    frame_count: usize,
+    frame_hop: f32,
+    emission: impl Fn(usize, Option<char>) -> f32,
+) -> Option<Vec<(String, f32, f32)>> {
+    let tokens: Vec<char> = transcript.chars().collect();
+    let token_spans = align_tokens(&tokens, frame_count, emission)?;
+
+    let mut words = Vec::new();
+    let mut current_word = String::new();
+    let mut current_span: Option<(usize, usize)> = None;
+
+    let mut flush = |word: &mut String, span: &mut Option<(usize, usize)>, words: &mut Vec<_>| {
+        if word.is_empty() {
+            return;
+        }
+        if let Some((start_frame, end_frame)) = span.take() {
+            words.push((
+                std::mem::take(word),
+                start_frame as f32 * frame_hop,
+                (end_frame + 1) as f32 * frame_hop,
+            ));
+        }
+    };
+
+    for (token, span) in tokens.iter().zip(token_spans.iter()) {
+        if token.is_whitespace() {
+            flush(&mut current_word, &mut current_span, &mut words);
+            continue;
+        }
+
+        let (start_frame, end_frame) = *span;
+        current_span = Some(match current_span {
+            Some((start, _)) => (start, end_frame),
+            None => (start_frame, end_frame),
+        });
+        current_word.push(*token);
+    }
+    flush(&mut current_word, &mut current_span, &mut words);
+
+    Some(words)
+}
@@ -1,18 +1,29 @@
-use axum::extract::State;
+use axum::extract::{FromRequest, Multipart, Request, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
 use axum_extra::TypedHeader;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
-use crate::error::OpenAiError;
+use crate::environ::Timeout;
+use crate::error::HttpError;
 use crate::headers::RequestId;
+use crate::huggingface::alignment;
+use crate::huggingface::chunking;
 use crate::huggingface::HuggingFaceRequest;
-use crate::openai::audio::AUDIO_TAG;
-use crate::{Context, OpenAiResult};
+use crate::AUDIO_TAG;
+use crate::{Context, HttpResult};
+use base64::Engine;
+use hfendpoints_core::environ::TryFromEnv;
 use hfendpoints_core::{EndpointContext, Error};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::instrument;
 use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -27,7 +38,7 @@ pub enum AutomaticSpeechRecognitionEarlyStoppingEnum {
 #[allow(unused)]
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(debug_assertions, derive(Debug))]
-#[derive(Clone, Copy, Deserialize, ToSchema)]
+#[derive(Clone, Copy, Default, Deserialize, ToSchema)]
 pub(crate) struct AutomaticSpeechRecognitionGenerationParams {
     /// Whether to use sampling instead of greedy decoding when generating new tokens.
     do_sample: Option<bool>,
@@ -92,6 +103,68 @@ pub(crate) struct AutomaticSpeechRecognitionGenerationParams {
     use_cache: Option<bool>,
 }
 
+/// Which task the underlying Whisper-family model should run the audio through.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticSpeechRecognitionTask {
+    /// Transcribe the audio in its original language.
+    Transcribe,
+
+    /// Translate the audio into English, regardless of the source language.
+    Translate,
+}
+
+impl Default for AutomaticSpeechRecognitionTask {
+    #[inline]
+    fn default() -> Self {
+        AutomaticSpeechRecognitionTask::Transcribe
+    }
+}
+
+/// How the transcription should be encoded in the HTTP response.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticSpeechRecognitionResponseFormat {
+    Json,
+    VerboseJson,
+    Text,
+    Srt,
+    Vtt,
+}
+
+impl Default for AutomaticSpeechRecognitionResponseFormat {
+    #[inline]
+    fn default() -> Self {
+        AutomaticSpeechRecognitionResponseFormat::Json
+    }
+}
+
+/// The granularity of the `timestamps` populated on each [`AutomaticSpeechRecognitionChunk`].
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    /// One `[start, end]` span per chunk the model decoded, as handed back by the handler.
+    Segment,
+
+    /// One `[start, end]` span per word, produced by forced-aligning the transcript against the
+    /// handler's per-frame emissions. Falls back to `segment` when those emissions aren't
+    /// attached to the response.
+    Word,
+}
+
+impl Default for TimestampGranularity {
+    #[inline]
+    fn default() -> Self {
+        TimestampGranularity::Segment
+    }
+}
+
 /// Additional inference parameters for Automatic Speech Recognition
 #[allow(unused)]
 #[cfg_attr(feature = "python", pyclass)]
@@ -103,6 +176,30 @@ pub struct AutomaticSpeechRecognitionParams {
 
     /// Whether to output corresponding timestamps with the generated text
     return_timestamps: bool,
+
+    /// Which task the model should run the audio through. Defaults to `transcribe`; the
+    /// `/audio/translations` route always forces this to `translate`, regardless of what the
+    /// client sent.
+    #[serde(default)]
+    pub(crate) task: AutomaticSpeechRecognitionTask,
+
+    /// How the response should be encoded. Defaults to `json`.
+    #[serde(default)]
+    pub(crate) response_format: AutomaticSpeechRecognitionResponseFormat,
+
+    /// Whether `chunks` should carry per-segment or per-word timestamps. Defaults to `segment`.
+    #[serde(default)]
+    pub(crate) timestamp_granularity: TimestampGranularity,
+
+    /// Length, in seconds, of each server-side chunking window. When set together with a WAV
+    /// upload, `predict` splits the audio into overlapping windows of this length instead of
+    /// decoding it as a single request, so recordings longer than the model's receptive window
+    /// (e.g. 30s for Whisper) can still be transcribed in one call.
+    pub(crate) chunk_length_s: Option<f32>,
+
+    /// Overlap, in seconds, shared between consecutive chunking windows. Ignored unless
+    /// `chunk_length_s` is also set; defaults to no overlap.
+    pub(crate) stride_length_s: Option<f32>,
 }
 
 /// Inputs for Automatic Speech Recognition inference
@@ -112,15 +209,198 @@ pub struct AutomaticSpeechRecognitionParams {
 pub struct AutomaticSpeechRecognitionChunk {
     /// The input audio data as a base64-encoded string.
     /// If no `parameters` are provided, you can also provide the audio data as a raw bytes payload.
-    text: String,
+    pub(crate) text: String,
 
     /// The start and end timestamps corresponding with the text
-    timestamps: Vec<f32>,
+    pub(crate) timestamps: Vec<f32>,
 }
 
 pub type AutomaticSpeechRecognitionRequest =
     HuggingFaceRequest<String, AutomaticSpeechRecognitionParams>;
 
+/// Audio container accepted by the multipart `file` upload, identified from its content type or,
+/// failing that, its file name extension.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum AudioContainer {
+    Wav,
+    Mp3,
+    Flac,
+    Ogg,
+}
+
+impl AudioContainer {
+    fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type {
+            "audio/wav" | "audio/x-wav" | "audio/wave" => Some(Self::Wav),
+            "audio/mpeg" | "audio/mp3" => Some(Self::Mp3),
+            "audio/flac" | "audio/x-flac" => Some(Self::Flac),
+            "audio/ogg" | "application/ogg" => Some(Self::Ogg),
+            _ => None,
+        }
+    }
+
+    fn from_file_name(file_name: &str) -> Option<Self> {
+        match file_name.rsplit('.').next()?.to_ascii_lowercase().as_str() {
+            "wav" => Some(Self::Wav),
+            "mp3" => Some(Self::Mp3),
+            "flac" => Some(Self::Flac),
+            "ogg" => Some(Self::Ogg),
+            _ => None,
+        }
+    }
+}
+
+impl AutomaticSpeechRecognitionRequest {
+    /// Builds a request from a `multipart/form-data` upload: a binary `file` part plus the usual
+    /// OpenAI `createTranscription` text fields. `model`, `language` and `response_format` are
+    /// accepted but not currently mapped onto [`AutomaticSpeechRecognitionParams`], so existing
+    /// OpenAI SDKs and `curl -F` uploads can target this endpoint without a 422 on unknown fields.
+    #[instrument(skip_all)]
+    async fn try_from_multipart(mut multipart: Multipart) -> HttpResult<Self> {
+        let mut file: HttpResult<Option<Vec<u8>>> = Ok(None);
+        let mut temperature: HttpResult<Option<f32>> = Ok(None);
+        let mut chunk_length_s: HttpResult<Option<f32>> = Ok(None);
+        let mut stride_length_s: HttpResult<Option<f32>> = Ok(None);
+        let mut return_timestamps = false;
+        let mut response_format = AutomaticSpeechRecognitionResponseFormat::default();
+        let mut timestamp_granularity = TimestampGranularity::default();
+
+        while let Some(field) = multipart.next_field().await? {
+            let name = field.name().unwrap().to_string();
+            match name.as_str() {
+                "file" => {
+                    let content_type = field.content_type().unwrap_or("unknown").to_string();
+                    let file_name = field.file_name().unwrap_or("unknown").to_string();
+
+                    if AudioContainer::from_content_type(&content_type)
+                        .or_else(|| AudioContainer::from_file_name(&file_name))
+                        .is_none()
+                    {
+                        return Err(HttpError::Validation(format!(
+                            "Unsupported audio container for '{file_name}' ({content_type}); \
+                             supported containers are: wav, mp3, flac, ogg"
+                        )));
+                    }
+
+                    file = Ok(Some(field.bytes().await?.to_vec()));
+                }
+                "model" | "language" => {
+                    field.text().await?;
+                }
+                "temperature" => temperature = Ok(Some(f32::from_str(&field.text().await?)?)),
+                "chunk_length_s" => {
+                    chunk_length_s = Ok(Some(f32::from_str(&field.text().await?)?))
+                }
+                "stride_length_s" => {
+                    stride_length_s = Ok(Some(f32::from_str(&field.text().await?)?))
+                }
+                "return_timestamps" => {
+                    return_timestamps = field.text().await?.parse().map_err(|_| {
+                        HttpError::Validation(String::from(
+                            "return_timestamps must be 'true' or 'false'",
+                        ))
+                    })?;
+                }
+                "response_format" => {
+                    response_format = match field.text().await?.as_str() {
+                        "json" => AutomaticSpeechRecognitionResponseFormat::Json,
+                        "verbose_json" => AutomaticSpeechRecognitionResponseFormat::VerboseJson,
+                        "text" => AutomaticSpeechRecognitionResponseFormat::Text,
+                        "srt" => AutomaticSpeechRecognitionResponseFormat::Srt,
+                        "vtt" => AutomaticSpeechRecognitionResponseFormat::Vtt,
+                        other => {
+                            return Err(HttpError::Validation(format!(
+                                "Unknown response_format: {other}. Possible values are: \
+                                 'json', 'verbose_json', 'text', 'srt', 'vtt'."
+                            )))
+                        }
+                    };
+                }
+                "timestamp_granularity" => {
+                    timestamp_granularity = match field.text().await?.as_str() {
+                        "segment" => TimestampGranularity::Segment,
+                        "word" => TimestampGranularity::Word,
+                        other => {
+                            return Err(HttpError::Validation(format!(
+                                "Unknown timestamp_granularity: {other}. Possible values are: \
+                                 'segment', 'word'."
+                            )))
+                        }
+                    };
+                }
+                _ => return Err(HttpError::Validation(format!("Unknown field: {name}"))),
+            }
+        }
+
+        let file = file?.ok_or_else(|| {
+            HttpError::Validation(String::from("Required parameter 'file' was not provided"))
+        })?;
+
+        Ok(Self::new(
+            base64::engine::general_purpose::STANDARD.encode(file),
+            AutomaticSpeechRecognitionParams {
+                generation_params: temperature?.map(|temperature| {
+                    AutomaticSpeechRecognitionGenerationParams {
+                        temperature: Some(temperature),
+                        ..Default::default()
+                    }
+                }),
+                return_timestamps,
+                task: AutomaticSpeechRecognitionTask::default(),
+                response_format,
+                timestamp_granularity,
+                chunk_length_s: chunk_length_s?,
+                stride_length_s: stride_length_s?,
+            },
+        ))
+    }
+}
+
+impl<S> FromRequest<S> for AutomaticSpeechRecognitionRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    #[instrument(skip_all)]
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_multipart = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("multipart/form-data"));
+
+        if is_multipart {
+            let multipart = Multipart::from_request(req, state).await?;
+            Self::try_from_multipart(multipart).await
+        } else {
+            let Json(request) = Json::<Self>::from_request(req, state)
+                .await
+                .map_err(|err| HttpError::Validation(err.to_string()))?;
+            Ok(request)
+        }
+    }
+}
+
+/// Per-frame token emission log-probabilities a handler can attach to its response so this
+/// endpoint can refine `chunks` down to word-level timestamps when `timestamp_granularity` asked
+/// for it. Absent this, `word` granularity silently falls back to the segment-level `chunks` the
+/// handler already produced.
+#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct AutomaticSpeechRecognitionAlignmentInput {
+    /// `emissions[frame][0]` is the blank/epsilon symbol's log-probability at that frame;
+    /// `emissions[frame][i + 1]` is `vocabulary[i]`'s.
+    emissions: Vec<Vec<f32>>,
+
+    /// The vocabulary symbols `emissions`' non-blank columns correspond to, in column order.
+    vocabulary: Vec<char>,
+
+    /// Duration, in seconds, each frame of `emissions` spans.
+    frame_hop: f32,
+}
+
 /// Outputs of inference for the Automatic Speech Recognition task
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -131,6 +411,168 @@ pub struct AutomaticSpeechRecognitionResponse {
 
     /// The start and end timestamps corresponding with the text
     chunks: Vec<AutomaticSpeechRecognitionChunk>,
+
+    /// Per-frame emissions the handler attached for word-level forced alignment, if any. Taken
+    /// and consumed while applying `timestamp_granularity`; never serialized back to the client.
+    #[serde(skip)]
+    alignment_input: Option<AutomaticSpeechRecognitionAlignmentInput>,
+}
+
+/// Formats `seconds` as a subtitle cue timestamp, using `fraction_separator` between the seconds
+/// and millisecond components (`,` for SRT, `.` for WebVTT).
+fn format_cue_timestamp(seconds: f32, fraction_separator: char) -> String {
+    let millis_total = (seconds.max(0.0) * 1000.0).round() as u64;
+    let hours = millis_total / 3_600_000;
+    let minutes = (millis_total / 60_000) % 60;
+    let secs = (millis_total / 1_000) % 60;
+    let millis = millis_total % 1_000;
+    format!("{hours:02}:{minutes:02}:{secs:02}{fraction_separator}{millis:03}")
+}
+
+/// Serializes `chunks` into SubRip cue blocks, one per chunk, numbered from 1.
+fn chunks_to_srt(chunks: &[AutomaticSpeechRecognitionChunk]) -> String {
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let start = chunk.timestamps.first().copied().unwrap_or(0.0);
+            let end = chunk.timestamps.get(1).copied().unwrap_or(start);
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                format_cue_timestamp(start, ','),
+                format_cue_timestamp(end, ','),
+                chunk.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Serializes `chunks` into a WebVTT cue track, preceded by the mandatory `WEBVTT` header.
+fn chunks_to_vtt(chunks: &[AutomaticSpeechRecognitionChunk]) -> String {
+    let cues = chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let start = chunk.timestamps.first().copied().unwrap_or(0.0);
+            let end = chunk.timestamps.get(1).copied().unwrap_or(start);
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                format_cue_timestamp(start, '.'),
+                format_cue_timestamp(end, '.'),
+                chunk.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("WEBVTT\n\n{cues}")
+}
+
+/// [`AutomaticSpeechRecognitionResponse`], encoded per the request's `response_format`.
+pub enum EncodedAutomaticSpeechRecognition {
+    Json(AutomaticSpeechRecognitionResponse),
+    Text(String),
+    Subtitles(String, &'static str),
+}
+
+impl IntoResponse for EncodedAutomaticSpeechRecognition {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Json(response) => Json::from(response).into_response(),
+            Self::Text(text) => text.into_response(),
+            Self::Subtitles(body, content_type) => {
+                ([(header::CONTENT_TYPE, content_type)], body).into_response()
+            }
+        }
+    }
+}
+
+impl AutomaticSpeechRecognitionResponse {
+    /// Builds the stitched response [`chunking::merge_windows`] produces out of its merged
+    /// `text`/`chunks`; there is no alignment input to carry over, since that's per-window and
+    /// already consumed before windows are merged.
+    pub(crate) fn from_merged_chunks(
+        text: String,
+        chunks: Vec<AutomaticSpeechRecognitionChunk>,
+    ) -> Self {
+        Self {
+            text,
+            chunks,
+            alignment_input: None,
+        }
+    }
+
+    /// Refines `chunks` down to word-level timestamps via forced alignment when `granularity` is
+    /// [`TimestampGranularity::Word`] and the handler attached `alignment_input`; otherwise
+    /// leaves the handler's segment-level `chunks` untouched.
+    pub(crate) fn with_timestamp_granularity(mut self, granularity: TimestampGranularity) -> Self {
+        let Some(input) = (granularity == TimestampGranularity::Word)
+            .then(|| self.alignment_input.take())
+            .flatten()
+        else {
+            return self;
+        };
+
+        let emission = |frame: usize, token: Option<char>| -> f32 {
+            match token {
+                None => input.emissions[frame][0],
+                Some(symbol) => input
+                    .vocabulary
+                    .iter()
+                    .position(|candidate| *candidate == symbol)
+                    .map(|index| input.emissions[frame][index + 1])
+                    .unwrap_or(f32::NEG_INFINITY),
+            }
+        };
+
+        if let Some(words) = alignment::align_words(
+            &self.text,
+            input.emissions.len(),
+            input.frame_hop,
+            emission,
+        ) {
+            self.chunks = words
+                .into_iter()
+                .map(|(word, start, end)| AutomaticSpeechRecognitionChunk {
+                    text: word,
+                    timestamps: vec![start, end],
+                })
+                .collect();
+        }
+
+        self
+    }
+
+    /// Encodes this response per `format`: `json`/`verbose_json` keep the structured chunk
+    /// list as-is, `text` returns just the concatenated transcript, and `srt`/`vtt` turn each
+    /// chunk's `timestamps` into subtitle cue blocks ready to feed straight into captioning
+    /// tooling, with the matching `Content-Type`.
+    pub(crate) fn encode_as(
+        self,
+        format: AutomaticSpeechRecognitionResponseFormat,
+    ) -> EncodedAutomaticSpeechRecognition {
+        match format {
+            AutomaticSpeechRecognitionResponseFormat::Json
+            | AutomaticSpeechRecognitionResponseFormat::VerboseJson => {
+                EncodedAutomaticSpeechRecognition::Json(self)
+            }
+            AutomaticSpeechRecognitionResponseFormat::Text => {
+                EncodedAutomaticSpeechRecognition::Text(self.text)
+            }
+            AutomaticSpeechRecognitionResponseFormat::Srt => {
+                EncodedAutomaticSpeechRecognition::Subtitles(
+                    chunks_to_srt(&self.chunks),
+                    "application/x-subrip",
+                )
+            }
+            AutomaticSpeechRecognitionResponseFormat::Vtt => {
+                EncodedAutomaticSpeechRecognition::Subtitles(chunks_to_vtt(&self.chunks), "text/vtt")
+            }
+        }
+    }
 }
 
 #[utoipa::path(
@@ -152,17 +594,57 @@ pub async fn predict(
     >,
     request_id: TypedHeader<RequestId>,
     request: AutomaticSpeechRecognitionRequest,
-) -> OpenAiResult<AutomaticSpeechRecognitionResponse> {
+) -> HttpResult<EncodedAutomaticSpeechRecognition> {
+    let response_format = request.parameters.response_format;
+    let timestamp_granularity = request.parameters.timestamp_granularity;
+    let stride_length_s = request.parameters.stride_length_s.unwrap_or(0.0);
+
     // Create request context
     let ctx = Context::new(request_id.0);
 
-    // Ask for the inference thread to handle it and wait for answers
-    let mut egress = state.schedule((request, ctx));
-    if let Some(response) = egress.recv().await {
-        Ok(response?)
+    // When `chunk_length_s` is set and the upload is a WAV file, split it into overlapping
+    // windows up front; otherwise fall through to scheduling the request as-is.
+    let windows = request
+        .parameters
+        .chunk_length_s
+        .filter(|chunk_length_s| *chunk_length_s > 0.0)
+        .and_then(|chunk_length_s| {
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(&request.inputs)
+                .ok()?;
+            let pcm = chunking::parse_wav_pcm(&decoded)?;
+            let windows = chunking::split_into_windows(&pcm, chunk_length_s, stride_length_s);
+            (windows.len() > 1).then_some(windows)
+        });
+
+    let response = if let Some(windows) = windows {
+        let mut window_responses = Vec::with_capacity(windows.len());
+        for (start_time_s, duration_s, wav) in windows {
+            let window_request = AutomaticSpeechRecognitionRequest::new(
+                base64::engine::general_purpose::STANDARD.encode(wav),
+                request.parameters,
+            );
+
+            // Ask for the inference thread to handle this window and wait for its answer
+            let mut egress = state.schedule((window_request, ctx.clone()))?;
+            let Some(response) = egress.recv().await else {
+                return Err(HttpError::NoResponse);
+            };
+            window_responses.push((start_time_s, duration_s, response?));
+        }
+        chunking::merge_windows(window_responses, stride_length_s)
     } else {
-        Err(OpenAiError::NoResponse)
-    }
+        // Ask for the inference thread to handle it and wait for answers
+        let mut egress = state.schedule((request, ctx))?;
+        let Some(response) = egress.recv().await else {
+            return Err(HttpError::NoResponse);
+        };
+        response?
+    };
+
+    Ok(response
+        .with_timestamp_granularity(timestamp_granularity)
+        .encode_as(response_format))
 }
 
 /// Helper factory to build
@@ -174,3 +656,12 @@ pub struct AutomaticSpeechRecognitionRouter(
         UnboundedSender<Result<AutomaticSpeechRecognitionResponse, Error>>,
     )>,
 );
+
+impl From<AutomaticSpeechRecognitionRouter> for OpenApiRouter {
+    fn from(value: AutomaticSpeechRecognitionRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(predict))
+            .with_state(EndpointContext::new(value.0))
+            .layer(Timeout::try_from_env().unwrap_or_default().layer_for("audio"))
+    }
+}
@@ -0,0 +1,185 @@
+//! Server-side long-form chunking: split a WAV upload into overlapping time windows so models
+//! with a fixed receptive window (e.g. Whisper's 30s) can transcribe recordings of any length,
+//! then stitch the per-window responses back together.
+
+use crate::huggingface::asr::{AutomaticSpeechRecognitionChunk, AutomaticSpeechRecognitionResponse};
+
+/// A WAV file's PCM format, plus the byte range its `data` chunk occupies.
+pub(crate) struct WavPcm<'a> {
+    sample_rate: u32,
+    channels: u16,
+    bits_per_sample: u16,
+    data: &'a [u8],
+}
+
+impl WavPcm<'_> {
+    fn block_align(&self) -> usize {
+        (self.channels as usize * (self.bits_per_sample as usize / 8)).max(1)
+    }
+
+    fn byte_rate(&self) -> usize {
+        self.sample_rate as usize * self.block_align()
+    }
+}
+
+/// Parses the minimal RIFF/WAVE structure needed to locate the `fmt ` and `data` chunks.
+/// Returns `None` for anything else (compressed containers, malformed headers) -- there's no
+/// decoder available here to fall back on, so callers should treat that as "can't chunk this".
+pub(crate) fn parse_wav_pcm(bytes: &[u8]) -> Option<WavPcm<'_>> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let mut sample_rate = None;
+    let mut channels = None;
+    let mut bits_per_sample = None;
+    let mut data = None;
+
+    let mut cursor = 12;
+    while cursor + 8 <= bytes.len() {
+        let id = &bytes[cursor..cursor + 4];
+        let size = u32::from_le_bytes(bytes[cursor + 4..cursor + 8].try_into().ok()?) as usize;
+        let body_start = cursor + 8;
+        let body_end = body_start.checked_add(size)?;
+        if body_end > bytes.len() {
+            break;
+        }
+
+        match id {
+            b"fmt " if size >= 16 => {
+                let body = &bytes[body_start..body_end];
+                channels = Some(u16::from_le_bytes(body[2..4].try_into().ok()?));
+                sample_rate = Some(u32::from_le_bytes(body[4..8].try_into().ok()?));
+                bits_per_sample = Some(u16::from_le_bytes(body[14..16].try_into().ok()?));
+            }
+            b"data" => data = Some(&bytes[body_start..body_end]),
+            _ => {}
+        }
+
+        // Chunks are padded to an even number of bytes.
+        cursor = body_end + (size % 2);
+    }
+
+    Some(WavPcm {
+        sample_rate: sample_rate?,
+        channels: channels?,
+        bits_per_sample: bits_per_sample?,
+        data: data?,
+    })
+}
+
+/// Builds a standalone canonical 44-byte-header WAV file wrapping `data`, so each window can be
+/// scheduled as its own self-contained upload.
+fn build_wav(sample_rate: u32, channels: u16, bits_per_sample: u16, data: &[u8]) -> Vec<u8> {
+    let block_align = channels * (bits_per_sample / 8);
+    let byte_rate = sample_rate * block_align as u32;
+
+    let mut wav = Vec::with_capacity(44 + data.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&byte_rate.to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&bits_per_sample.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(data);
+    wav
+}
+
+/// Splits `pcm`'s audio into overlapping `chunk_length_s`-second windows (sharing
+/// `stride_length_s` seconds between consecutive windows), each re-wrapped as its own standalone
+/// WAV file alongside the window's start time and duration, in seconds.
+pub(crate) fn split_into_windows(
+    pcm: &WavPcm<'_>,
+    chunk_length_s: f32,
+    stride_length_s: f32,
+) -> Vec<(f32, f32, Vec<u8>)> {
+    let block_align = pcm.block_align();
+    let byte_rate = pcm.byte_rate();
+
+    let chunk_len = (((chunk_length_s as f64 * byte_rate as f64) as usize) / block_align
+        * block_align)
+        .max(block_align);
+    let stride_len = ((((stride_length_s.max(0.0)) as f64 * byte_rate as f64) as usize)
+        / block_align
+        * block_align)
+        .min(chunk_len / 2);
+    let step = chunk_len.saturating_sub(stride_len).max(block_align);
+
+    let mut windows = Vec::new();
+    let mut start = 0;
+    while start < pcm.data.len() {
+        let end = (start + chunk_len).min(pcm.data.len());
+        let start_time_s = start as f32 / byte_rate as f32;
+        let duration_s = (end - start) as f32 / byte_rate as f32;
+
+        windows.push((
+            start_time_s,
+            duration_s,
+            build_wav(
+                pcm.sample_rate,
+                pcm.channels,
+                pcm.bits_per_sample,
+                &pcm.data[start..end],
+            ),
+        ));
+
+        if end == pcm.data.len() {
+            break;
+        }
+        start += step;
+    }
+
+    windows
+}
+
+/// Stitches per-window [`AutomaticSpeechRecognitionResponse`]s back into one response: for every
+/// window but the first, chunks starting in its first `stride_length_s / 2` seconds are dropped
+/// (already covered by the previous window's second half-stride); for every window but the last,
+/// chunks starting in its last `stride_length_s / 2` seconds are dropped (covered by the next
+/// window's first half-stride). The surviving chunks have their timestamps offset by the
+/// window's start time and are concatenated in order.
+pub(crate) fn merge_windows(
+    windows: Vec<(f32, f32, AutomaticSpeechRecognitionResponse)>,
+    stride_length_s: f32,
+) -> AutomaticSpeechRecognitionResponse {
+    let last_index = windows.len().saturating_sub(1);
+    let half_stride = stride_length_s.max(0.0) / 2.0;
+
+    let mut chunks = Vec::new();
+    for (index, (start_time_s, duration_s, response)) in windows.into_iter().enumerate() {
+        for chunk in response.chunks {
+            let local_start = chunk.timestamps.first().copied().unwrap_or(0.0);
+
+            if index > 0 && local_start < half_stride {
+                continue;
+            }
+            if index < last_index && local_start >= duration_s - half_stride {
+                continue;
+            }
+
+            chunks.push(AutomaticSpeechRecognitionChunk {
+                text: chunk.text,
+                timestamps: chunk
+                    .timestamps
+                    .iter()
+                    .map(|timestamp| timestamp + start_time_s)
+                    .collect(),
+            });
+        }
+    }
+
+    let text = chunks
+        .iter()
+        .map(|chunk| chunk.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    AutomaticSpeechRecognitionResponse::from_merged_chunks(text, chunks)
+}
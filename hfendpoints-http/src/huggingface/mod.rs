@@ -2,7 +2,10 @@ use serde::Deserialize;
 use std::fmt::Debug;
 use utoipa::ToSchema;
 
+mod alignment;
 pub(crate) mod asr;
+mod chunking;
+pub(crate) mod translation;
 
 /// Generic representation of requests sent from Hugging Face inference definition.
 ///
@@ -13,6 +16,82 @@ where
     I: Debug + ToSchema,
     P: Debug + ToSchema,
 {
-    inputs: I,
-    parameters: P,
+    pub(crate) inputs: I,
+    pub(crate) parameters: P,
+}
+
+impl<I, P> HuggingFaceRequest<I, P>
+where
+    I: Debug + ToSchema,
+    P: Debug + ToSchema,
+{
+    pub(crate) fn new(inputs: I, parameters: P) -> Self {
+        Self { inputs, parameters }
+    }
+}
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::huggingface::asr::{
+        AutomaticSpeechRecognitionAlignmentInput, AutomaticSpeechRecognitionChunk,
+        AutomaticSpeechRecognitionParams, AutomaticSpeechRecognitionResponse,
+        AutomaticSpeechRecognitionResponseFormat, AutomaticSpeechRecognitionTask,
+    };
+    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use pyo3::prelude::*;
+
+    mod asrs {
+        use crate::huggingface::asr::{
+            AutomaticSpeechRecognitionRequest, AutomaticSpeechRecognitionResponse,
+            AutomaticSpeechRecognitionRouter,
+        };
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(
+            AutomaticSpeechRecognitionRequest,
+            AutomaticSpeechRecognitionResponse
+        );
+        impl_http_pyendpoint!(
+            "AutomaticSpeechRecognitionEndpoint",
+            PyAutomaticSpeechRecognitionEndpoint,
+            PyHandler,
+            AutomaticSpeechRecognitionRouter
+        );
+    }
+
+    mod translations {
+        use crate::huggingface::asr::{
+            AutomaticSpeechRecognitionRequest, AutomaticSpeechRecognitionResponse,
+        };
+        use crate::huggingface::translation::TranslationRouter;
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(
+            AutomaticSpeechRecognitionRequest,
+            AutomaticSpeechRecognitionResponse
+        );
+        impl_http_pyendpoint!(
+            "TranslationEndpoint",
+            PyTranslationEndpoint,
+            PyHandler,
+            TranslationRouter
+        );
+    }
+
+    /// Bind hfendpoints.http.huggingface submodule into the exported Python wheel
+    pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
+        let module = ImportablePyModuleBuilder::new(py, name)?
+            .defaults()?
+            .add_class::<AutomaticSpeechRecognitionTask>()?
+            .add_class::<AutomaticSpeechRecognitionResponseFormat>()?
+            .add_class::<AutomaticSpeechRecognitionParams>()?
+            .add_class::<AutomaticSpeechRecognitionChunk>()?
+            .add_class::<AutomaticSpeechRecognitionAlignmentInput>()?
+            .add_class::<AutomaticSpeechRecognitionResponse>()?
+            .add_class::<asrs::PyAutomaticSpeechRecognitionEndpoint>()?
+            .add_class::<translations::PyTranslationEndpoint>()?
+            .finish();
+
+        Ok(module)
+    }
 }
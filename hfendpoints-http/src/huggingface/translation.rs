@@ -0,0 +1,81 @@
+use axum::extract::State;
+use axum_extra::TypedHeader;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use crate::environ::Timeout;
+use crate::error::HttpError;
+use crate::headers::RequestId;
+use crate::huggingface::asr::{
+    AutomaticSpeechRecognitionRequest, AutomaticSpeechRecognitionResponse,
+    AutomaticSpeechRecognitionTask, EncodedAutomaticSpeechRecognition,
+};
+use crate::AUDIO_TAG;
+use crate::{Context, HttpResult};
+use hfendpoints_core::environ::TryFromEnv;
+use hfendpoints_core::{EndpointContext, Error};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// Translates audio in any source language into English, reusing the Automatic Speech
+/// Recognition request/response pair, with [`AutomaticSpeechRecognitionTask::Translate`] forced
+/// onto the scheduled request so the Python handler picks the translate decode mode regardless
+/// of what the client's `task` parameter said.
+#[utoipa::path(
+    post,
+    path = "/audio/translations",
+    tag = AUDIO_TAG,
+    request_body(content = AutomaticSpeechRecognitionRequest, content_type = "application/json"),
+    responses(
+        (status = OK, description = "Translates audio into English.", body = AutomaticSpeechRecognitionResponse),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn predict(
+    State(state): State<
+        EndpointContext<
+            (AutomaticSpeechRecognitionRequest, Context),
+            AutomaticSpeechRecognitionResponse,
+        >,
+    >,
+    request_id: TypedHeader<RequestId>,
+    mut request: AutomaticSpeechRecognitionRequest,
+) -> HttpResult<EncodedAutomaticSpeechRecognition> {
+    request.parameters.task = AutomaticSpeechRecognitionTask::Translate;
+    let response_format = request.parameters.response_format;
+    let timestamp_granularity = request.parameters.timestamp_granularity;
+
+    // Create request context
+    let ctx = Context::new(request_id.0);
+
+    // Ask for the inference thread to handle it and wait for answers
+    let mut egress = state.schedule((request, ctx))?;
+    if let Some(response) = egress.recv().await {
+        Ok(response?
+            .with_timestamp_granularity(timestamp_granularity)
+            .encode_as(response_format))
+    } else {
+        Err(HttpError::NoResponse)
+    }
+}
+
+/// Helper factory to build
+/// [OpenAi Platform compatible Translation endpoint](https://platform.openai.com/docs/api-reference/audio/createTranslation)
+#[derive(Clone)]
+pub struct TranslationRouter(
+    pub  UnboundedSender<(
+        (AutomaticSpeechRecognitionRequest, Context),
+        UnboundedSender<Result<AutomaticSpeechRecognitionResponse, Error>>,
+    )>,
+);
+
+impl From<TranslationRouter> for OpenApiRouter {
+    fn from(value: TranslationRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(predict))
+            .with_state(EndpointContext::new(value.0))
+            .layer(Timeout::try_from_env().unwrap_or_default().layer_for("audio"))
+    }
+}
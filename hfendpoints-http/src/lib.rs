@@ -1,9 +1,6 @@
 use crate::headers::X_REQUEST_ID_NAME;
-use std::fmt::Debug;
-use tokio::net::{TcpListener, ToSocketAddrs};
 use tower::ServiceBuilder;
 use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
-use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::TraceLayer;
 use tracing::instrument;
 use utoipa::OpenApi;
@@ -11,19 +8,34 @@ use utoipa_axum::router::OpenApiRouter;
 use utoipa_scalar::{Scalar, Servable};
 
 pub(crate) mod api;
+mod audio;
 mod context;
+mod driver;
 pub mod environ;
 pub mod error;
+pub mod extract;
+mod huggingface;
 pub mod headers;
+pub mod listener;
+pub mod metrics;
+pub mod negotiation;
+mod openai;
 pub mod routes;
+mod sample;
 
 pub use crate::api::ApiDoc;
 use crate::environ::Timeout;
+use crate::metrics::{Metrics, MetricsLayer, MetricsRouter};
 use crate::routes::StatusRouter;
 pub use context::Context;
+pub use driver::Driver;
 pub use error::HttpError;
+pub use extract::ValidatedJson;
 use hfendpoints_core::environ::TryFromEnv;
-use hfendpoints_core::Error;
+use hfendpoints_core::{Error, HealthSnapshot};
+pub use listener::{Bindable, ServeAddress};
+pub use negotiation::{Negotiated, ResponseFormat};
+use std::sync::Arc;
 
 pub type HttpResult<T> = Result<T, HttpError>;
 pub type RequestWithContext<I> = (I, Context);
@@ -37,15 +49,25 @@ pub const AUDIO_DESC: &str = "Learn how to turn audio into text or text into aud
 pub const EMBEDDINGS_TAG: &str = "Embeddings";
 pub const EMBEDDINGS_DESC: &str = "Get a vector representation of a given input that can be easily consumed by machine learning models and algorithms.";
 
-#[instrument(skip(task_router))]
-pub async fn serve_http<A, R>(interface: A, task_router: R) -> HttpResult<()>
+#[instrument(skip(task_router, driver, health))]
+pub async fn serve_http<L, R>(
+    interface: L,
+    task_router: R,
+    driver: Driver,
+    health: Option<tokio::sync::watch::Receiver<HealthSnapshot>>,
+) -> HttpResult<()>
 where
-    A: ToSocketAddrs + Debug,
+    L: Bindable,
     R: Into<OpenApiRouter>,
 {
-    // Retrieve the timeout duration from envvar
+    // Per-route-group timeout policy: task routers (audio, embeddings, ...) each apply their own
+    // `Timeout::layer_for(tag)` where they're assembled, since a health check and a
+    // minutes-long transcription have nothing in common; this function only owns the bound for
+    // the status/metrics routes it builds itself, plus the graceful-drain window below.
     let timeout = Timeout::try_from_env().map_err(Error::Environment)?;
 
+    let metrics = Arc::new(Metrics::new());
+
     // Default routes
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .merge(task_router.into())
@@ -54,16 +76,23 @@ where
                 .layer(TraceLayer::new_for_http())
                 .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
                 .layer(PropagateRequestIdLayer::new(X_REQUEST_ID_NAME.clone()))
-                .layer(TimeoutLayer::from(timeout)),
+                .layer(MetricsLayer::new(Arc::clone(&metrics))),
+        )
+        .merge(StatusRouter(health).into().layer(timeout.layer_for("status")))
+        .merge(
+            MetricsRouter(metrics.registry().clone())
+                .into()
+                .layer(timeout.layer_for("status")),
         )
-        .merge(StatusRouter.into())
         .split_for_parts();
 
     // Documentation route
     let router = router.merge(Scalar::with_url("/docs", api));
 
-    let listener = TcpListener::bind(interface).await?;
-    axum::serve(listener, router).await?;
+    let listener = interface.bind().await?;
+    axum::serve(listener, router)
+        .with_graceful_shutdown(driver.shutdown_signal_with_drain(timeout.graceful_drain))
+        .await?;
     Ok(())
 }
 
@@ -172,49 +201,493 @@ pub mod python {
                     // Execute the coroutine on Python
                     let response = self.execute_coroutine(coroutine, locals).await?;
 
-                    // Attempt to convert back the output to the original frontend-specific message
+                    // Attempt to convert back the output to the original frontend-facing message
+                    response.0.try_into()
+                }
+            }
+        };
+    }
+
+    /// Alternative to [`impl_http_pyhandler!`] for a `__call__` that isn't a coroutine: instead of
+    /// being driven through `pyo3_async_runtimes` and the shared `TASK_LOCALS` event loop, it
+    /// returns a [`RustPromise`] wrapping a tokio task it has already spawned itself, so handlers
+    /// written without `async`/`await` -- plain threads, notebooks, frameworks with no running
+    /// event loop -- can still back an endpoint.
+    ///
+    /// No in-tree endpoint invokes this macro: every handler `hfendpoints-openai`/
+    /// `hfendpoints-audio` ships is `async`, so they go through [`impl_http_pyhandler!`] instead.
+    /// This one is kept as public API for out-of-tree Python binding authors whose `__call__`
+    /// can't be a coroutine; it isn't dead code, just unexercised by this repo's own endpoints.
+    #[macro_export]
+    macro_rules! impl_http_pyhandler_blocking {
+        ($request: ident, $response: ident, $pyrequest: ident, $pyresponse: ident) => {
+            use hfendpoints_core::{EndpointResult, Error, Handler, HandlerError::Implementation};
+            use pyo3::exceptions::PyRuntimeError;
+            use pyo3::prelude::*;
+            use tokio::task::JoinHandle;
+            use tracing::{debug, error, info, instrument};
+            use $crate::Context;
+
+            /// Handed back by a non-asyncio `__call__` in place of an awaitable: wraps the
+            /// `JoinHandle` of a task it already spawned onto the tokio runtime. [`Self::pyawait`]
+            /// lets synchronous Python code block on it directly, with the GIL released, instead
+            /// of going through `TASK_LOCALS`.
+            #[pyclass]
+            pub struct RustPromise {
+                handle: Option<JoinHandle<PyResult<$pyresponse>>>,
+            }
+
+            impl RustPromise {
+                pub fn new(handle: JoinHandle<PyResult<$pyresponse>>) -> Self {
+                    Self {
+                        handle: Some(handle),
+                    }
+                }
+
+                fn take_handle(&mut self) -> PyResult<JoinHandle<PyResult<$pyresponse>>> {
+                    self.handle
+                        .take()
+                        .ok_or_else(|| PyRuntimeError::new_err("RustPromise can only be awaited once"))
+                }
+            }
+
+            #[pymethods]
+            impl RustPromise {
+                /// Block the calling Python thread, with the GIL released, until the tokio task
+                /// backing this promise completes, then return its typed response.
+                #[instrument(skip_all)]
+                fn pyawait(&mut self, py: Python<'_>) -> PyResult<$pyresponse> {
+                    let handle = self.take_handle()?;
+
+                    py.allow_threads(|| {
+                        pyo3_async_runtimes::tokio::get_runtime()
+                            .block_on(handle)
+                            .map_err(|err| PyRuntimeError::new_err(err.to_string()))?
+                    })
+                }
+            }
+
+            #[pyclass(subclass)]
+            pub struct PyHandler {
+                inner: PyObject,
+            }
+
+            impl Handler for PyHandler {
+                type Request = ($request, Context);
+                type Response = $response;
+
+                async fn on_request(
+                    &self,
+                    request: Self::Request,
+                ) -> Result<Self::Response, Error> {
+                    let (request, context) = request;
+
+                    debug!("[INGRESS] request: {request:?}");
+
+                    // Convert the underlying frontend-specific message to the I/O adapter layer
+                    let request = $pyrequest(request.try_into()?);
+
+                    debug!("[INGRESS] successfully converted request");
+
+                    // `__call__` runs synchronously and is expected to have already spawned its
+                    // own work onto the tokio runtime, handing back a `RustPromise` instead of a
+                    // coroutine to drive through `TASK_LOCALS`.
+                    let promise = Python::with_gil(|py| {
+                        self.inner
+                            .call1(py, (request, context))?
+                            .extract::<Py<RustPromise>>(py)
+                    })
+                    .map_err(|err| {
+                        error!("Failed to retrieve RustPromise from __call__: {err}");
+                        Error::from(Implementation(err.to_string().into()))
+                    })?;
+
+                    let handle = Python::with_gil(|py| promise.borrow_mut(py).take_handle())
+                        .map_err(|err| Error::from(Implementation(err.to_string().into())))?;
+
+                    debug!("[NATIVE] non-asyncio Handler's RustPromise created");
+
+                    let response = handle
+                        .await
+                        .map_err(|err| Error::from(Implementation(err.to_string().into())))?
+                        .map_err(|err| Error::from(Implementation(err.to_string().into())))?;
+
+                    debug!("[NATIVE] non-asyncio Handler's RustPromise resolved");
+
+                    // Attempt to convert back the output to the original frontend-facing message
                     response.0.try_into()
                 }
             }
         };
     }
 
+    /// Like [`impl_http_pyhandler!`], but for a `$request`/`$response` pair that's already a
+    /// pyclass in its own right instead of wrapping a `hfendpoints-tasks` type behind a newtype --
+    /// e.g. the `audio`/`huggingface` endpoints, which have no shared task-layer representation to
+    /// convert to and from. Skips the `$pyrequest(request.try_into()?)` wrap/unwrap step that
+    /// `impl_http_pyhandler!` needs for that conversion, everything else is identical.
+    #[macro_export]
+    macro_rules! impl_http_pyhandler_direct {
+        ($request: ident, $response: ident) => {
+            use hfendpoints_core::{EndpointResult, Error, Handler, HandlerError::Implementation};
+            use pyo3::exceptions::PyRuntimeError;
+            use pyo3::prelude::*;
+            use pyo3_async_runtimes::TaskLocals;
+            use std::process;
+            use tokio::sync::OnceCell;
+            use tracing::{debug, error, info, instrument};
+            use $crate::Context;
+            use $crate::python::TASK_LOCALS;
+
+            #[pyclass(subclass)]
+            pub struct PyHandler {
+                inner: PyObject,
+            }
+
+            impl PyHandler {
+                fn materialize_coroutine(
+                    &self,
+                    request: $request,
+                    context: Context,
+                    locals: &TaskLocals,
+                ) -> EndpointResult<impl Future<Output = PyResult<PyObject>> + Send + 'static> {
+                    Python::with_gil(|py| {
+                        let py_coro_call = self.inner.call1(py, (request, context))?.into_bound(py);
+
+                        debug!("[NATIVE] asyncio Handler's coroutine (__call__) created");
+                        pyo3_async_runtimes::into_future_with_locals(&locals, py_coro_call)
+                    })
+                    .map_err(|err| {
+                        error!("Failed to retrieve __call__ coroutine: {err}");
+                        Error::from(Implementation(err.to_string().into()))
+                    })
+                }
+
+                async fn execute_coroutine(
+                    &self,
+                    coroutine: impl Future<Output = PyResult<PyObject>> + Send + 'static,
+                    locals: TaskLocals,
+                ) -> PyResult<$response> {
+                    pyo3_async_runtimes::tokio::get_runtime()
+                        .spawn(async {
+                            // Schedule the coroutine
+                            let response =
+                                match pyo3_async_runtimes::tokio::scope(locals, coroutine).await {
+                                    Ok(resp) => resp,
+                                    Err(err) => {
+                                        error!("Failed to execute __call__: {err}");
+                                        return Err(err);
+                                    }
+                                };
+
+                            debug!("[NATIVE] asyncio Handler's coroutine (__call__) done");
+
+                            // The coroutine's return value already is the response type
+                            match Python::with_gil(|py| response.extract::<$response>(py)) {
+                                Ok(resp) => Ok(resp),
+                                Err(err) => Err(err),
+                            }
+                        })
+                        .await
+                        .map_err(|err| PyRuntimeError::new_err(err.to_string()))?
+                }
+            }
+
+            impl Handler for PyHandler {
+                type Request = ($request, Context);
+                type Response = $response;
+
+                async fn on_request(
+                    &self,
+                    request: Self::Request,
+                ) -> Result<Self::Response, Error> {
+                    // Retrieve the current event loop
+                    let locals = Python::with_gil(|py| TASK_LOCALS.get().unwrap().clone_ref(py));
+                    let (request, context) = request;
+
+                    debug!("[INGRESS] request: {request:?}");
+
+                    // Create the coroutine on the Python side to await through tokio
+                    let coroutine = self.materialize_coroutine(request, context, &locals)?;
+
+                    // Execute the coroutine on Python and hand its response straight back; unlike
+                    // `impl_http_pyhandler!` there's no frontend-facing conversion to apply
+                    Ok(self.execute_coroutine(coroutine, locals).await?)
+                }
+            }
+        };
+    }
+
+    /// Raised by a Python middleware callable to deliberately reject a request, e.g.
+    /// `raise MiddlewareException("missing token", 401)`. Caught by `impl_http_pymiddleware!` and
+    /// translated into `MiddlewareError::Rejected`, which `HttpError` maps to the named status
+    /// (400 if none was given) instead of the 500 any other exception gets.
+    pyo3::create_exception!(
+        hfendpoints_http,
+        MiddlewareException,
+        pyo3::exceptions::PyException
+    );
+
+    /// Bridges an ordered Python request-middleware callable (sync or async) into
+    /// `hfendpoints_core::Middleware`, so endpoints can run auth, request rewriting, or caching
+    /// in Python ahead of `on_request` without touching Rust.
+    ///
+    /// A callable is run with `(request,)` and its return value interpreted the same way as
+    /// smithy-rs middleware: `None` leaves the request untouched, a `$request` replaces it for
+    /// the next stage, and a `$response` short-circuits the pipeline. Raising
+    /// [`MiddlewareException`] rejects the request; any other exception is treated as a bug in
+    /// the middleware.
+    #[macro_export]
+    macro_rules! impl_http_pymiddleware {
+        ($request: ident, $response: ident) => {
+            use hfendpoints_core::{Middleware, MiddlewareError, MiddlewareOutcome};
+            use pyo3::prelude::*;
+            use std::future::Future;
+            use std::pin::Pin;
+            use tracing::error;
+            use $crate::python::{MiddlewareException, TASK_LOCALS};
+
+            enum MiddlewareInvocation {
+                Ready(PyObject),
+                Pending(Pin<Box<dyn Future<Output = PyResult<PyObject>> + Send>>),
+            }
+
+            fn translate_exception(err: PyErr) -> MiddlewareError {
+                Python::with_gil(|py| {
+                    if err.is_instance_of::<MiddlewareException>(py) {
+                        match err.value(py).getattr("args").and_then(|args| {
+                            args.extract::<(String, Option<u16>)>()
+                        }) {
+                            Ok((message, status)) => MiddlewareError::Rejected(message.into(), status),
+                            Err(_) => MiddlewareError::Rejected(err.to_string().into(), None),
+                        }
+                    } else {
+                        MiddlewareError::Failed(err.to_string().into())
+                    }
+                })
+            }
+
+            #[pyclass(subclass)]
+            pub struct PyMiddleware {
+                inner: PyObject,
+            }
+
+            #[pymethods]
+            impl PyMiddleware {
+                #[new]
+                fn new(inner: PyObject) -> Self {
+                    Self { inner }
+                }
+            }
+
+            impl PyMiddleware {
+                fn invoke(&self, request: &$request) -> PyResult<MiddlewareInvocation> {
+                    let locals = Python::with_gil(|py| TASK_LOCALS.get().unwrap().clone_ref(py));
+
+                    Python::with_gil(|py| {
+                        let result = self.inner.call1(py, (request.clone(),))?.into_bound(py);
+
+                        if result.is_none() {
+                            Ok(MiddlewareInvocation::Ready(py.None()))
+                        } else if result.hasattr("__await__")? {
+                            let future =
+                                pyo3_async_runtimes::into_future_with_locals(&locals, result)?;
+                            Ok(MiddlewareInvocation::Pending(Box::pin(future)))
+                        } else {
+                            Ok(MiddlewareInvocation::Ready(result.unbind()))
+                        }
+                    })
+                }
+            }
+
+            impl Middleware<$request, $response> for PyMiddleware {
+                fn on_request(
+                    &self,
+                    request: $request,
+                ) -> Pin<
+                    Box<
+                        dyn Future<Output = Result<MiddlewareOutcome<$request, $response>, MiddlewareError>>
+                            + Send,
+                    >,
+                > {
+                    let invocation = self.invoke(&request).map_err(|err| {
+                        error!("Middleware __call__ raised: {err}");
+                        translate_exception(err)
+                    });
+
+                    Box::pin(async move {
+                        let result = match invocation? {
+                            MiddlewareInvocation::Ready(value) => value,
+                            MiddlewareInvocation::Pending(future) => future.await.map_err(|err| {
+                                error!("Middleware coroutine raised: {err}");
+                                translate_exception(err)
+                            })?,
+                        };
+
+                        Python::with_gil(|py| {
+                            if result.is_none(py) {
+                                return Ok(MiddlewareOutcome::Continue(request));
+                            }
+
+                            if let Ok(response) = result.extract::<$response>(py) {
+                                return Ok(MiddlewareOutcome::Respond(response));
+                            }
+
+                            match result.extract::<$request>(py) {
+                                Ok(request) => Ok(MiddlewareOutcome::Continue(request)),
+                                Err(err) => Err(translate_exception(err)),
+                            }
+                        })
+                    })
+                }
+            }
+        };
+    }
+
     #[macro_export]
     macro_rules! impl_http_pyendpoint {
         ($name: literal, $pyname: ident, $handler: ident, $router: ident) => {
-            use hfendpoints_core::{Endpoint, wait_for_requests};
+            use hfendpoints_core::environ::{Concurrency, TryFromEnv};
+            use hfendpoints_core::{Endpoint, wait_for_requests, HealthReporter};
             use pyo3::prelude::*;
             use pyo3::types::PyNone;
             use std::sync::Arc;
-            use tokio::net::TcpListener;
             use tokio::sync::mpsc::unbounded_channel;
             use tokio::task::spawn;
             use utoipa::OpenApi;
             use utoipa_axum::{router::OpenApiRouter, routes};
             use $crate::routes::{__path_health, health};
-            use $crate::{ApiDoc, serve_http};
+            use $crate::{ApiDoc, serve_http, Driver, ServeAddress};
 
             #[pyclass(name = $name)]
-            pub(crate) struct $pyname(Arc<$handler>);
+            pub(crate) struct $pyname(Arc<$handler>, Driver);
 
-            impl Endpoint<(String, u16)> for $pyname {
+            impl Endpoint<ServeAddress> for $pyname {
                 #[instrument(skip_all)]
-                async fn serve(&self, inet_address: (String, u16)) -> Result<(), Error> {
+                async fn serve(&self, address: ServeAddress) -> Result<(), Error> {
                     let (sender, receiver) = unbounded_channel();
                     let router = $router { 0: sender };
 
                     // Handler in another thread
                     let handler = Arc::clone(&self.0);
-                    let _ = pyo3_async_runtimes::tokio::get_runtime()
-                        .spawn(wait_for_requests(receiver, handler));
+                    let concurrency = Concurrency::try_from_env().map_err(Error::Environment)?;
+                    // Lets `/health` reflect this handler's actual liveness instead of always
+                    // answering OK; the reporter side is updated after every completed request.
+                    let (health_reporter, health) = HealthReporter::new();
+                    // No middleware is registered yet; `PyMiddleware` built from
+                    // `impl_http_pymiddleware!` slots in here once an endpoint wires one up.
+                    let _ = pyo3_async_runtimes::tokio::get_runtime().spawn(wait_for_requests(
+                        receiver,
+                        handler,
+                        Vec::new(),
+                        concurrency,
+                        Arc::new(health_reporter),
+                    ));
+
+                    info!("Starting endpoint at {:?}", &address);
+
+                    match pyo3_async_runtimes::tokio::get_runtime()
+                        .spawn(serve_http(address, router, self.1.clone(), Some(health)))
+                        .await
+                    {
+                        Ok(res) => Ok(res?),
+                        Err(join_error) => Err(Error::Runtime(join_error.to_string().into())),
+                    }
+                }
+            }
+
+            #[pymethods]
+            impl $pyname {
+                #[instrument(skip(inner))]
+                #[new]
+                fn new(inner: PyObject) -> Self {
+                    Self(Arc::new($handler { inner }), Driver::new())
+                }
+
+                #[instrument(skip_all)]
+                async fn _serve_(&self, interface: String, port: u16) -> PyResult<()> {
+                    let address = ServeAddress::parse(&interface, port);
+                    if let Err(err) = self.serve(address).await {
+                        error!("Caught error while serving HTTP endpoint: {err}");
+                        Err(PyRuntimeError::new_err(err.to_string()))
+                    } else {
+                        Ok(())
+                    }
+                }
+
+                /// Begin a graceful shutdown: stop accepting new connections and return from
+                /// `_serve_`/`run` once in-flight requests have drained.
+                #[instrument(skip_all)]
+                fn stop(&self) {
+                    self.1.stop();
+                }
+            }
+        };
+    }
+
+    /// Like [`impl_http_pyendpoint!`], but inserts a [`hfendpoints_core::batch_requests`] stage
+    /// between the router's ingress channel and `wait_for_requests`, coalescing concurrently
+    /// arriving requests into one handler call via `$batcher` (an expression constructing an
+    /// `Arc<dyn Batcher<$router's request, $router's response>>`). Use this for endpoints whose
+    /// handler amortizes better over a batch than one request at a time (e.g. embedding models);
+    /// everything else should keep using the unbatched [`impl_http_pyendpoint!`].
+    #[macro_export]
+    macro_rules! impl_http_pyendpoint_batched {
+        ($name: literal, $pyname: ident, $handler: ident, $router: ident, $batcher: expr) => {
+            use hfendpoints_core::environ::{Concurrency, TryFromEnv};
+            use hfendpoints_core::{batch_requests, BatchingPolicy, Endpoint, wait_for_requests, HealthReporter};
+            use pyo3::prelude::*;
+            use pyo3::types::PyNone;
+            use std::sync::Arc;
+            use tokio::sync::mpsc::unbounded_channel;
+            use tokio::task::spawn;
+            use utoipa::OpenApi;
+            use utoipa_axum::{router::OpenApiRouter, routes};
+            use $crate::routes::{__path_health, health};
+            use $crate::{ApiDoc, serve_http, Driver, ServeAddress};
 
-                    info!(
-                        "Starting endpoint at {}:{}",
-                        &inet_address.0, &inet_address.1
-                    );
+            #[pyclass(name = $name)]
+            pub(crate) struct $pyname(Arc<$handler>, Driver);
+
+            impl Endpoint<ServeAddress> for $pyname {
+                #[instrument(skip_all)]
+                async fn serve(&self, address: ServeAddress) -> Result<(), Error> {
+                    let (sender, receiver) = unbounded_channel();
+                    let router = $router { 0: sender };
+
+                    // Handler in another thread
+                    let handler = Arc::clone(&self.0);
+                    let concurrency = Concurrency::try_from_env().map_err(Error::Environment)?;
+                    let batching = BatchingPolicy::try_from_env().map_err(Error::Environment)?;
+                    // Lets `/health` reflect this handler's actual liveness instead of always
+                    // answering OK; the reporter side is updated after every completed request.
+                    let (health_reporter, health) = HealthReporter::new();
+
+                    // Coalesces requests arriving close together before they ever reach
+                    // `wait_for_requests`, so the handler sees one batched call instead of one
+                    // per caller.
+                    let (batched_sender, batched_receiver) = unbounded_channel();
+                    let _ = pyo3_async_runtimes::tokio::get_runtime().spawn(batch_requests(
+                        receiver,
+                        batched_sender,
+                        $batcher,
+                        batching,
+                    ));
+
+                    // No middleware is registered yet; `PyMiddleware` built from
+                    // `impl_http_pymiddleware!` slots in here once an endpoint wires one up.
+                    let _ = pyo3_async_runtimes::tokio::get_runtime().spawn(wait_for_requests(
+                        batched_receiver,
+                        handler,
+                        Vec::new(),
+                        concurrency,
+                        Arc::new(health_reporter),
+                    ));
+
+                    info!("Starting endpoint at {:?}", &address);
 
                     match pyo3_async_runtimes::tokio::get_runtime()
-                        .spawn(serve_http(inet_address, router))
+                        .spawn(serve_http(address, router, self.1.clone(), Some(health)))
                         .await
                     {
                         Ok(res) => Ok(res?),
@@ -228,18 +701,26 @@ pub mod python {
                 #[instrument(skip(inner))]
                 #[new]
                 fn new(inner: PyObject) -> Self {
-                    Self(Arc::new($handler { inner }))
+                    Self(Arc::new($handler { inner }), Driver::new())
                 }
 
                 #[instrument(skip_all)]
                 async fn _serve_(&self, interface: String, port: u16) -> PyResult<()> {
-                    if let Err(err) = self.serve((interface, port)).await {
+                    let address = ServeAddress::parse(&interface, port);
+                    if let Err(err) = self.serve(address).await {
                         error!("Caught error while serving HTTP endpoint: {err}");
                         Err(PyRuntimeError::new_err(err.to_string()))
                     } else {
                         Ok(())
                     }
                 }
+
+                /// Begin a graceful shutdown: stop accepting new connections and return from
+                /// `_serve_`/`run` once in-flight requests have drained.
+                #[instrument(skip_all)]
+                fn stop(&self) {
+                    self.1.stop();
+                }
             }
         };
     }
@@ -298,9 +779,16 @@ pub mod python {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
             .add_class::<Context>()?
+            .add_submodule(&crate::audio::python::bind(py, &format!("{name}.audio"))?)?
+            .add_submodule(&crate::huggingface::python::bind(
+                py,
+                &format!("{name}.huggingface"),
+            )?)?
+            .add_submodule(&crate::openai::python::bind(py, &format!("{name}.openai"))?)?
             .finish();
 
         module.add_function(wrap_pyfunction!(run, &module)?)?;
+        module.add("MiddlewareException", py.get_type::<MiddlewareException>())?;
 
         Ok(module)
     }
@@ -0,0 +1,192 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context as TaskContext, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpStream, UnixListener, UnixStream};
+
+/// Something `serve_http` can turn into a listener `axum::serve` can accept connections from.
+///
+/// This lets `serve_http` stay generic over the transport: the built-in [`ServeAddress`] binds
+/// either a TCP socket or a Unix domain socket, but any caller-supplied type implementing
+/// `Bindable` (e.g. a pre-bound listener handed down from a supervisor process) works too.
+pub trait Bindable: Send {
+    type Listener: axum::serve::Listener;
+
+    fn bind(self) -> impl Future<Output = io::Result<Self::Listener>> + Send;
+}
+
+impl Bindable for (String, u16) {
+    type Listener = TcpListener;
+
+    async fn bind(self) -> io::Result<Self::Listener> {
+        TcpListener::bind(self).await
+    }
+}
+
+/// Either half of a connection accepted from a [`ServeListener`]: a TCP stream or a Unix domain
+/// socket stream, so `serve_http`'s router can be generic over the transport.
+pub enum ServeIo {
+    Tcp(TcpStream),
+    Unix(UnixStream),
+}
+
+impl AsyncRead for ServeIo {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            ServeIo::Tcp(io) => Pin::new(io).poll_read(cx, buf),
+            ServeIo::Unix(io) => Pin::new(io).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for ServeIo {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            ServeIo::Tcp(io) => Pin::new(io).poll_write(cx, buf),
+            ServeIo::Unix(io) => Pin::new(io).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            ServeIo::Tcp(io) => Pin::new(io).poll_flush(cx),
+            ServeIo::Unix(io) => Pin::new(io).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            ServeIo::Tcp(io) => Pin::new(io).poll_shutdown(cx),
+            ServeIo::Unix(io) => Pin::new(io).poll_shutdown(cx),
+        }
+    }
+}
+
+/// A listener bound to either a TCP socket or a Unix domain socket.
+///
+/// Removing the socket file on drop keeps a cleanly-shut-down endpoint from leaving a stale
+/// `.sock` file behind for the next `unix:` bind to trip over.
+pub enum ServeListener {
+    Tcp(TcpListener),
+    Unix(UnixListener, PathBuf),
+}
+
+impl Drop for ServeListener {
+    fn drop(&mut self) {
+        if let ServeListener::Unix(_, path) = self {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+impl axum::serve::Listener for ServeListener {
+    type Io = ServeIo;
+    type Addr = String;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            let accepted = match self {
+                ServeListener::Tcp(listener) => listener
+                    .accept()
+                    .await
+                    .map(|(io, addr)| (ServeIo::Tcp(io), addr.to_string())),
+                ServeListener::Unix(listener, _) => listener.accept().await.map(|(io, addr)| {
+                    let addr = addr
+                        .as_pathname()
+                        .map(|p| p.display().to_string())
+                        .unwrap_or_else(|| "unix:unnamed".to_string());
+                    (ServeIo::Unix(io), addr)
+                }),
+            };
+
+            match accepted {
+                Ok(accepted) => return accepted,
+                // Mirrors `tokio::net::TcpListener`'s own `axum::serve::Listener` impl: a failed
+                // accept doesn't take down the whole endpoint, just that one connection attempt.
+                Err(_) => continue,
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        match self {
+            ServeListener::Tcp(listener) => listener.local_addr().map(|addr| addr.to_string()),
+            ServeListener::Unix(listener, _) => listener.local_addr().map(|addr| {
+                addr.as_pathname()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "unix:unnamed".to_string())
+            }),
+        }
+    }
+}
+
+/// A transport `serve_http` can bind to: a TCP `(host, port)` pair, or a Unix domain socket path.
+///
+/// Parsed from the same `(interface, port)` pair the Python binding accepts: an `interface` of
+/// the form `unix:/path/to/socket` binds a Unix domain socket (the `port` is ignored), anything
+/// else binds TCP.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone)]
+pub enum ServeAddress {
+    Tcp(String, u16),
+    Unix(PathBuf),
+}
+
+impl ServeAddress {
+    pub fn parse(interface: &str, port: u16) -> Self {
+        match interface.strip_prefix("unix:") {
+            Some(path) => Self::Unix(PathBuf::from(path)),
+            None => Self::Tcp(interface.to_string(), port),
+        }
+    }
+
+    pub fn unix(path: impl AsRef<Path>) -> Self {
+        Self::Unix(path.as_ref().to_path_buf())
+    }
+}
+
+impl Bindable for ServeAddress {
+    type Listener = ServeListener;
+
+    async fn bind(self) -> io::Result<Self::Listener> {
+        match self {
+            Self::Tcp(host, port) => Ok(ServeListener::Tcp(TcpListener::bind((host, port)).await?)),
+            Self::Unix(path) => {
+                // Binding over a stale socket file left behind by an unclean shutdown would
+                // otherwise fail with `AddrInUse`.
+                if path.exists() {
+                    std::fs::remove_file(&path)?;
+                }
+
+                let listener = UnixListener::bind(&path)?;
+                Ok(ServeListener::Unix(listener, path))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tcp() {
+        let address = ServeAddress::parse("0.0.0.0", 8080);
+        assert!(matches!(address, ServeAddress::Tcp(host, 8080) if host == "0.0.0.0"));
+    }
+
+    #[test]
+    fn test_parse_unix() {
+        let address = ServeAddress::parse("unix:/tmp/hfendpoints.sock", 8080);
+        assert!(matches!(address, ServeAddress::Unix(path) if path == PathBuf::from("/tmp/hfendpoints.sock")));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::STATUS_TAG;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use hfendpoints_core::InFlightStats;
+use opentelemetry::metrics::{Histogram, UpDownCounter};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use prometheus::{Encoder, Registry, TextEncoder};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+use std::time::Instant;
+use tower::{Layer, Service};
+use tracing::{error, instrument};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// Backs the `/metrics` Prometheus scrape route and the [`MetricsLayer`] tower layer: the
+/// OpenTelemetry instruments described by `huggingface/hfendpoints#chunk1-3` (up/down counters for
+/// live in-flight and queued requests, observed-maxima gauges, and a per-route request-duration
+/// histogram), plus the [`Registry`] they're collected into.
+pub struct Metrics {
+    stats: Arc<InFlightStats>,
+    in_flight_counter: UpDownCounter<i64>,
+    in_queue_counter: UpDownCounter<i64>,
+    request_duration: Histogram<f64>,
+    registry: Registry,
+
+    // Kept alive for as long as `Metrics` is: dropping the provider stops the instruments from
+    // being collected into `registry`.
+    _provider: SdkMeterProvider,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        let exporter = opentelemetry_prometheus::exporter()
+            .with_registry(registry.clone())
+            .build()
+            .expect("failed to build the Prometheus exporter");
+        let provider = SdkMeterProvider::builder().with_reader(exporter).build();
+        let meter = provider.meter("hfendpoints");
+
+        let stats = Arc::new(InFlightStats::default());
+
+        {
+            let stats = Arc::clone(&stats);
+            meter
+                .u64_observable_gauge("hfendpoints.http.requests.max_in_flight")
+                .with_description("Highest number of requests handled concurrently so far")
+                .with_callback(move |observer| observer.observe(stats.max_in_flight() as u64, &[]))
+                .build();
+        }
+        {
+            let stats = Arc::clone(&stats);
+            meter
+                .u64_observable_gauge("hfendpoints.http.requests.max_in_queue")
+                .with_description("Highest number of requests waiting to be dispatched so far")
+                .with_callback(move |observer| observer.observe(stats.max_in_queue() as u64, &[]))
+                .build();
+        }
+
+        let in_flight_counter = meter
+            .i64_up_down_counter("hfendpoints.http.requests.in_flight")
+            .with_description("Number of requests currently being handled")
+            .build();
+        let in_queue_counter = meter
+            .i64_up_down_counter("hfendpoints.http.requests.in_queue")
+            .with_description("Number of requests accepted but not yet dispatched to a handler")
+            .build();
+        let request_duration = meter
+            .f64_histogram("hfendpoints.http.request.duration")
+            .with_description("Request handling duration, in seconds, by route")
+            .with_unit("s")
+            .build();
+
+        Self {
+            stats,
+            in_flight_counter,
+            in_queue_counter,
+            request_duration,
+            registry,
+            _provider: provider,
+        }
+    }
+
+    /// A request was accepted by the listener and is waiting to be dispatched.
+    fn on_accepted(&self) {
+        self.stats.enqueue();
+        self.in_queue_counter.add(1, &[]);
+    }
+
+    /// A queued request is now being handled.
+    fn on_dispatched(&self) {
+        self.stats.dequeue_to_in_flight();
+        self.in_queue_counter.add(-1, &[]);
+        self.in_flight_counter.add(1, &[]);
+    }
+
+    /// A request finished; `route` and `elapsed` feed the duration histogram.
+    fn on_completed(&self, route: &str, elapsed: std::time::Duration) {
+        self.in_flight_counter.add(-1, &[]);
+        self.request_duration
+            .record(elapsed.as_secs_f64(), &[KeyValue::new("route", route.to_string())]);
+    }
+
+    /// The Prometheus registry the OpenTelemetry instruments above are collected into; handed to
+    /// the `/metrics` route so it can encode a scrape response from it.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `tower` layer that feeds [`Metrics`] from a request's lifecycle: `in_queue` is incremented as
+/// soon as the request is accepted, moved to `in_flight` once the inner service actually starts
+/// handling it, and decremented on completion. The request's span (already correlated to its
+/// `x-request-id` by `PropagateRequestIdLayer`) carries the route for the duration histogram, so
+/// the request id itself never becomes a high-cardinality metric label.
+#[derive(Clone)]
+pub struct MetricsLayer {
+    metrics: Arc<Metrics>,
+}
+
+impl MetricsLayer {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService {
+            inner,
+            metrics: Arc::clone(&self.metrics),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    metrics: Arc<Metrics>,
+}
+
+impl<S> Service<Request<Body>> for MetricsService<S>
+where
+    S: Service<Request<Body>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let route = request.uri().path().to_string();
+        let metrics = Arc::clone(&self.metrics);
+        metrics.on_accepted();
+
+        // `Service::call` requires the returned future to drive the actual work, so `inner` is
+        // swapped for a ready clone and dispatch-tracking happens inside it, right before
+        // `inner.call` is awaited.
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            metrics.on_dispatched();
+            let start = Instant::now();
+            let response = inner.call(request).await;
+            metrics.on_completed(&route, start.elapsed());
+            response
+        })
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = STATUS_TAG,
+    responses(
+        (status = OK, description = "Prometheus text-format scrape of this endpoint's metrics", body = str, content_type = "text/plain")
+    )
+)]
+#[instrument(skip(registry))]
+async fn metrics(State(registry): State<Registry>) -> impl IntoResponse {
+    let encoder = TextEncoder::new();
+    let families = registry.gather();
+
+    let mut buffer = Vec::new();
+    if let Err(e) = encoder.encode(&families, &mut buffer) {
+        error!("Failed to encode Prometheus metrics: {e}");
+        return (StatusCode::INTERNAL_SERVER_ERROR, String::new());
+    }
+
+    match String::from_utf8(buffer) {
+        Ok(body) => (StatusCode::OK, body),
+        Err(e) => {
+            error!("Prometheus metrics output was not valid UTF-8: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, String::new())
+        }
+    }
+}
+
+/// Provides the `/metrics` Prometheus scrape route, merged into `serve_http`'s router alongside
+/// [`crate::routes::StatusRouter`].
+pub struct MetricsRouter(pub Registry);
+
+impl From<MetricsRouter> for OpenApiRouter {
+    fn from(value: MetricsRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(metrics))
+            .with_state(value.0)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::HttpError;
+use axum::http::{header, HeaderMap};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+/// A representation this crate knows how to serialize a response into, picked from the
+/// request's `Accept` header. Defaults to JSON when the header is absent, `*/*`, or names
+/// nothing this crate supports.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    Json,
+    MessagePack,
+    Cbor,
+}
+
+impl ResponseFormat {
+    const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MessagePack => "application/msgpack",
+            Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Picks the first supported media type out of `headers`' `Accept` value, mirroring
+    /// gotham_restful's response negotiation: unset, empty, or `*/*` means "no preference",
+    /// which defaults to JSON.
+    pub fn negotiate(headers: &HeaderMap) -> Result<Self, HttpError> {
+        let Some(accept) = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()) else {
+            return Ok(Self::Json);
+        };
+
+        accept
+            .split(',')
+            .map(|candidate| candidate.split(';').next().unwrap_or("").trim())
+            .filter(|candidate| !candidate.is_empty())
+            .map(|candidate| match candidate {
+                "*/*" | "application/json" => Some(Self::Json),
+                "application/msgpack" | "application/x-msgpack" => Some(Self::MessagePack),
+                "application/cbor" => Some(Self::Cbor),
+                _ => None,
+            })
+            .next()
+            .unwrap_or(Some(Self::Json))
+            .ok_or_else(|| HttpError::NotAcceptable(accept.to_string()))
+    }
+}
+
+/// Wraps any serializable response with the [`ResponseFormat`] [`ResponseFormat::negotiate`]
+/// picked for it, so a single response type can serve JSON, MessagePack, or CBOR depending on
+/// what the caller asked for -- in particular large tensors from audio/embedding endpoints,
+/// which are far more compact outside JSON.
+pub struct Negotiated<T>(pub T, pub ResponseFormat);
+
+impl<T> Negotiated<T> {
+    pub fn new(response: T, headers: &HeaderMap) -> Result<Self, HttpError> {
+        Ok(Self(response, ResponseFormat::negotiate(headers)?))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Negotiated<T> {
+    fn into_response(self) -> Response {
+        let Self(response, format) = self;
+
+        let body = match format {
+            ResponseFormat::Json => serde_json::to_vec(&response).map_err(|e| e.to_string()),
+            ResponseFormat::MessagePack => {
+                rmp_serde::to_vec_named(&response).map_err(|e| e.to_string())
+            }
+            ResponseFormat::Cbor => {
+                let mut buffer = Vec::new();
+                serde_cbor::to_writer(&mut buffer, &response)
+                    .map(|_| buffer)
+                    .map_err(|e| e.to_string())
+            }
+        };
+
+        match body {
+            Ok(body) => ([(header::CONTENT_TYPE, format.content_type())], body).into_response(),
+            Err(e) => HttpError::Validation(e).into_response(),
+        }
+    }
+}
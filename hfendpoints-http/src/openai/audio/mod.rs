@@ -1,3 +1,4 @@
+pub(crate) mod speech;
 pub(crate) mod transcription;
 
 pub const AUDIO_TAG: &str = "Audio";
@@ -5,9 +6,11 @@ pub const AUDIO_DESC: &str = "Learn how to turn audio into text or text into aud
 
 #[cfg(feature = "python")]
 pub(crate) mod python {
+    use crate::openai::audio::speech::{SpeechResponseFormat, TextToSpeechRequest, TextToSpeechResponse};
     use crate::openai::audio::transcription::python::TranscriptionResponseKind;
     use crate::openai::audio::transcription::{
-        Segment, Transcription, TranscriptionRequest, TranscriptionResponse, VerboseTranscription,
+        Segment, TimestampGranularity, Transcription, TranscriptionParams, TranscriptionRequest,
+        TranscriptionResponse, TranscriptionTask, VerboseTranscription, Word,
     };
     use hfendpoints_binding_python::ImportablePyModuleBuilder;
     use pyo3::prelude::*;
@@ -16,10 +19,10 @@ pub(crate) mod python {
         use crate::openai::audio::transcription::{
             TranscriptionRequest, TranscriptionResponse, TranscriptionRouter,
         };
-        use crate::python::{impl_pyendpoint, impl_pyhandler};
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
 
-        impl_pyhandler!(TranscriptionRequest, TranscriptionResponse);
-        impl_pyendpoint!(
+        impl_http_pyhandler_direct!(TranscriptionRequest, TranscriptionResponse);
+        impl_http_pyendpoint!(
             "AutomaticSpeechRecognitionEndpoint",
             PyAutomaticSpeechRecognitionEndpoint,
             PyHandler,
@@ -27,18 +30,40 @@ pub(crate) mod python {
         );
     }
 
+    mod speeches {
+        use crate::openai::audio::speech::{TextToSpeechRequest, TextToSpeechResponse, TextToSpeechRouter};
+        use crate::{impl_http_pyendpoint, impl_http_pyhandler_direct};
+
+        impl_http_pyhandler_direct!(TextToSpeechRequest, TextToSpeechResponse);
+        impl_http_pyendpoint!(
+            "TextToSpeechEndpoint",
+            PyTextToSpeechEndpoint,
+            PyHandler,
+            TextToSpeechRouter
+        );
+    }
+
     /// Bind hfendpoints.http.audio submodule into the exported Python wheel
     pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
             // transcription
             .add_class::<Segment>()?
+            .add_class::<Word>()?
             .add_class::<Transcription>()?
             .add_class::<VerboseTranscription>()?
+            .add_class::<TranscriptionTask>()?
+            .add_class::<TimestampGranularity>()?
+            .add_class::<TranscriptionParams>()?
             .add_class::<TranscriptionRequest>()?
             .add_class::<TranscriptionResponse>()?
             .add_class::<TranscriptionResponseKind>()?
             .add_class::<transcriptions::PyAutomaticSpeechRecognitionEndpoint>()?
+            // speech
+            .add_class::<SpeechResponseFormat>()?
+            .add_class::<TextToSpeechRequest>()?
+            .add_class::<TextToSpeechResponse>()?
+            .add_class::<speeches::PyTextToSpeechEndpoint>()?
             .finish();
 
         Ok(module)
@@ -0,0 +1,220 @@
+use crate::context::Context;
+use crate::headers::RequestId;
+use crate::openai::audio::AUDIO_TAG;
+use crate::sample::FromSample;
+use crate::{HttpError, HttpResult, RequestWithContext};
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum_extra::TypedHeader;
+use hfendpoints_core::{EndpointContext, EndpointResult};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::StreamExt;
+use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Container/codec the synthesized speech should be encoded as.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechResponseFormat {
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    Pcm,
+}
+
+impl Default for SpeechResponseFormat {
+    #[inline]
+    fn default() -> Self {
+        SpeechResponseFormat::Mp3
+    }
+}
+
+impl SpeechResponseFormat {
+    const fn content_type(self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+            Self::Aac => "audio/aac",
+            Self::Flac => "audio/flac",
+            Self::Wav => "audio/wav",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// Inputs for the OpenAI-compatible text-to-speech endpoint.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "model": null,
+    "input": String::from_sample(TextToSpeechRequest::SAMPLE),
+    "voice": "alloy",
+    "response_format": "mp3",
+    "speed": 1.0,
+}))]
+pub struct TextToSpeechRequest {
+    /// The text-to-speech model to use.
+    model: Option<String>,
+
+    /// The text to generate audio for. Limited to 4096 characters.
+    input: String,
+
+    /// The voice to synthesize the audio with.
+    voice: Option<String>,
+
+    /// The format the synthesized audio should be encoded as. Defaults to `mp3`.
+    response_format: Option<SpeechResponseFormat>,
+
+    /// The speed of the generated audio, between `0.25` and `4.0`. Defaults to `1.0`.
+    speed: Option<f32>,
+}
+
+impl FromSample for TextToSpeechRequest {
+    const SAMPLE: &'static [u8] = b"Hello from Hugging Face Inference Endpoints!";
+
+    fn from_sample(bytes: &[u8]) -> Self {
+        Self {
+            model: None,
+            input: String::from_sample(bytes),
+            voice: Some(String::from("alloy")),
+            response_format: Some(SpeechResponseFormat::Mp3),
+            speed: Some(1.0),
+        }
+    }
+}
+
+type TextToSpeechRequestWithContext = RequestWithContext<TextToSpeechRequest>;
+
+/// Encoded audio produced by the text-to-speech model, along with the format it was encoded as.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct TextToSpeechResponse {
+    /// The synthesized audio bytes, encoded per `format`.
+    #[schema(format = Binary)]
+    audio: Vec<u8>,
+
+    /// The format `audio` is encoded as.
+    format: SpeechResponseFormat,
+}
+
+impl IntoResponse for TextToSpeechResponse {
+    fn into_response(self) -> Response {
+        (
+            [(header::CONTENT_TYPE, self.format.content_type())],
+            self.audio,
+        )
+            .into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/audio/speech",
+    tag = AUDIO_TAG,
+    request_body(content = TextToSpeechRequest, content_type = "application/json"),
+    responses(
+        (status = OK, description = "Synthesizes speech audio for the input text.", body = TextToSpeechResponse),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn predict(
+    State(state): State<EndpointContext<TextToSpeechRequestWithContext, TextToSpeechResponse>>,
+    request_id: TypedHeader<RequestId>,
+    request: TextToSpeechRequest,
+) -> HttpResult<TextToSpeechResponse> {
+    // Create request context
+    let ctx = Context::new(request_id.0);
+
+    // Ask for the inference thread to handle it and wait for answers
+    let mut egress = state.schedule((request, ctx));
+    if let Some(response) = egress.recv().await {
+        Ok(response?)
+    } else {
+        Err(HttpError::NoResponse)
+    }
+}
+
+/// Like [`predict`], but forwards each chunk the model produces as soon as it is available,
+/// instead of waiting for the full audio to be synthesized: the handler pushes intermediate
+/// [`TextToSpeechResponse`] chunks through `on_stream_request`'s `partial` sender, and this route
+/// relays their `audio` bytes to the client as an HTTP chunked transfer.
+#[utoipa::path(
+    post,
+    path = "/audio/speech/stream",
+    tag = AUDIO_TAG,
+    request_body(content = TextToSpeechRequest, content_type = "application/json"),
+    responses(
+        (status = OK, description = "Synthesizes speech audio for the input text, streaming encoded audio chunks as they become available."),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn predict_stream(
+    State(state): State<EndpointContext<TextToSpeechRequestWithContext, TextToSpeechResponse>>,
+    request_id: TypedHeader<RequestId>,
+    request: TextToSpeechRequest,
+) -> HttpResult<Response> {
+    let ctx = Context::new(request_id.0);
+    let format = request.response_format.unwrap_or_default();
+
+    let egress = state.schedule((request, ctx))?;
+    let chunks = UnboundedReceiverStream::new(egress).map(|chunk| {
+        chunk
+            .map(|response| response.audio)
+            .map_err(std::io::Error::other)
+    });
+
+    Ok((
+        [(header::CONTENT_TYPE, format.content_type())],
+        Body::from_stream(chunks),
+    )
+        .into_response())
+}
+
+/// Helper factory to build
+/// [OpenAi Platform compatible Speech endpoint](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+#[derive(Clone)]
+pub struct TextToSpeechRouter(
+    pub  UnboundedSender<(
+        TextToSpeechRequestWithContext,
+        UnboundedSender<EndpointResult<TextToSpeechResponse>>,
+    )>,
+);
+
+impl From<TextToSpeechRouter> for OpenApiRouter {
+    fn from(value: TextToSpeechRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(predict))
+            .routes(routes!(predict_stream))
+            .with_state(EndpointContext::new(value.0))
+    }
+}
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::openai::audio::speech::{SpeechResponseFormat, TextToSpeechResponse};
+    use pyo3::prelude::*;
+
+    #[pymethods]
+    impl TextToSpeechResponse {
+        #[new]
+        pub fn new(audio: Vec<u8>, format: SpeechResponseFormat) -> Self {
+            Self { audio, format }
+        }
+    }
+}
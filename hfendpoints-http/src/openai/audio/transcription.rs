@@ -0,0 +1,268 @@
+use crate::context::Context;
+use crate::headers::RequestId;
+use crate::openai::audio::AUDIO_TAG;
+use crate::{HttpError, HttpResult, RequestWithContext};
+use axum::extract::State;
+use axum_extra::TypedHeader;
+use hfendpoints_core::{EndpointContext, EndpointResult};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Which task the multi-task speech model should run the audio through.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionTask {
+    /// Transcribe the audio in its original language.
+    Transcribe,
+
+    /// Translate the audio into `target_language` (English when unset).
+    Translate,
+}
+
+impl Default for TranscriptionTask {
+    #[inline]
+    fn default() -> Self {
+        TranscriptionTask::Transcribe
+    }
+}
+
+/// Granularity of the timestamps the model should attach to its output.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    Segment,
+    Word,
+}
+
+/// One segment of the transcribed text and the corresponding details.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct Segment {
+    /// Unique identifier of the segment.
+    id: u16,
+
+    /// Start time of the segment in seconds.
+    start: f32,
+
+    /// End time of the segment in seconds.
+    end: f32,
+
+    /// Text content of the segment.
+    text: String,
+}
+
+/// A single word and the time range it was spoken in, emitted when
+/// `timestamp_granularities` includes `word`.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct Word {
+    /// The text content of the word.
+    text: String,
+
+    /// Start time of the word in seconds.
+    start: f32,
+
+    /// End time of the word in seconds.
+    end: f32,
+}
+
+/// Describe all the parameters to tune the underlying multi-task speech model
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Default, Deserialize, ToSchema)]
+pub struct TranscriptionParams {
+    /// The language of the input audio, in ISO-639-1 (e.g. `en`) format.
+    ///
+    /// When unset, the model detects the spoken language on its own.
+    language: Option<String>,
+
+    /// Whether the model should transcribe the audio in its original language, or translate it.
+    task: Option<TranscriptionTask>,
+
+    /// The language `task = translate` should produce output in. Defaults to English.
+    target_language: Option<String>,
+
+    /// Which timestamp granularities to populate on the `verbose_json` response.
+    timestamp_granularities: Option<Vec<TimestampGranularity>>,
+}
+
+/// Represents a transcription response returned by the model, based on the provided input.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct Transcription {
+    /// The transcribed (or translated) text.
+    text: String,
+}
+
+/// Represents a verbose json transcription response returned by the model, based on the
+/// provided input.
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct VerboseTranscription {
+    /// The transcribed (or translated) text.
+    text: String,
+
+    /// The detected (or requested) language of the input audio.
+    language: String,
+
+    /// Segment-level timestamps, always populated.
+    segments: Vec<Segment>,
+
+    /// Word-level timestamps, populated only when requested through `timestamp_granularities`.
+    words: Option<Vec<Word>>,
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub enum TranscriptionResponse {
+    Json(Transcription),
+    VerboseJson(VerboseTranscription),
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Deserialize, ToSchema)]
+pub struct TranscriptionRequest {
+    /// The audio file data, base64-encoded, to transcribe or translate.
+    inputs: String,
+
+    /// Parameters tuning the underlying multi-task speech model.
+    parameters: TranscriptionParams,
+}
+
+type TranscriptionRequestWithContext = RequestWithContext<TranscriptionRequest>;
+
+#[utoipa::path(
+    post,
+    path = "/audio/transcriptions",
+    tag = AUDIO_TAG,
+    request_body(content = TranscriptionRequest, content_type = "application/json"),
+    responses(
+        (status = OK, description = "Transcribes or translates audio into the requested language.", body = TranscriptionResponse),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn transcribe(
+    State(state): State<EndpointContext<TranscriptionRequestWithContext, TranscriptionResponse>>,
+    request_id: TypedHeader<RequestId>,
+    request: TranscriptionRequest,
+) -> HttpResult<TranscriptionResponse> {
+    // Create request context
+    let ctx = Context::new(request_id.0);
+
+    // Ask for the inference thread to handle it and wait for answers
+    let mut egress = state.schedule((request, ctx));
+    if let Some(response) = egress.recv().await {
+        Ok(response?)
+    } else {
+        Err(HttpError::NoResponse)
+    }
+}
+
+/// Helper factory to build
+/// [OpenAi Platform compatible Transcription endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription)
+#[derive(Clone)]
+pub struct TranscriptionRouter(
+    pub  UnboundedSender<(
+        TranscriptionRequestWithContext,
+        UnboundedSender<EndpointResult<TranscriptionResponse>>,
+    )>,
+);
+
+impl From<TranscriptionRouter> for OpenApiRouter {
+    fn from(value: TranscriptionRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(transcribe))
+            .with_state(EndpointContext::new(value.0))
+    }
+}
+
+#[cfg(feature = "python")]
+pub(crate) mod python {
+    use crate::openai::audio::transcription::{
+        Segment, Transcription, TranscriptionResponse, VerboseTranscription, Word,
+    };
+    use pyo3::prelude::*;
+
+    #[pyclass(frozen, eq, eq_int)]
+    #[derive(Eq, PartialEq)]
+    pub enum TranscriptionResponseKind {
+        #[pyo3(name = "JSON")]
+        Json = 1,
+
+        #[pyo3(name = "VERBOSE_JSON")]
+        VerboseJson = 2,
+    }
+
+    #[pymethods]
+    impl Segment {
+        #[new]
+        pub fn new(id: u16, start: f32, end: f32, text: String) -> Self {
+            Self { id, start, end, text }
+        }
+    }
+
+    #[pymethods]
+    impl Word {
+        #[new]
+        pub fn new(text: String, start: f32, end: f32) -> Self {
+            Self { text, start, end }
+        }
+    }
+
+    #[pymethods]
+    impl Transcription {
+        #[new]
+        pub fn new(text: String) -> Self {
+            Self { text }
+        }
+    }
+
+    #[pymethods]
+    impl VerboseTranscription {
+        #[new]
+        #[pyo3(signature = (text, language, segments, words=None))]
+        pub fn new(
+            text: String,
+            language: String,
+            segments: Vec<Segment>,
+            words: Option<Vec<Word>>,
+        ) -> Self {
+            Self {
+                text,
+                language,
+                segments,
+                words,
+            }
+        }
+    }
+
+    #[pymethods]
+    impl TranscriptionResponse {
+        #[staticmethod]
+        fn json(content: String) -> Self {
+            Self::Json(Transcription { text: content })
+        }
+
+        #[staticmethod]
+        fn verbose(transcription: VerboseTranscription) -> Self {
+            Self::VerboseJson(transcription)
+        }
+    }
+}
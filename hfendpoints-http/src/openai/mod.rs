@@ -1,18 +1,17 @@
-mod transcription;
+pub(crate) mod audio;
 
 #[cfg(feature = "python")]
 pub(crate) mod python {
-    use crate::audio::transcription;
     use hfendpoints_binding_python::ImportablePyModuleBuilder;
-    use pyo3::prelude::PyModule;
-    use pyo3::{Bound, PyResult, Python};
+    use pyo3::prelude::*;
 
+    /// Bind hfendpoints.http.openai submodule into the exported Python wheel
     pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
-            .add_submodule(&transcription::python::bind(
+            .add_submodule(&crate::openai::audio::python::bind(
                 py,
-                &format!("{name}.transcription"),
+                &format!("{name}.audio"),
             )?)?
             .finish();
 
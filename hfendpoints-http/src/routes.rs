@@ -1,29 +1,48 @@
 use crate::STATUS_TAG;
+use axum::extract::State;
 use axum::http::StatusCode;
+use hfendpoints_core::HealthSnapshot;
+use std::time::Duration;
+use tokio::sync::watch;
 use tracing::instrument;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+/// How recently the handler must have pushed a [`HealthSnapshot`] for `/health` to consider it
+/// live; a handler that's wedged or has panicked stops reporting altogether and falls outside
+/// this window.
+const LIVENESS_WINDOW: Duration = Duration::from_secs(30);
+
 #[utoipa::path(
     method(get, head),
     path = "/health",
     tag = STATUS_TAG,
     responses(
-        (status = OK, description = "Success", body = str, content_type = "application/json")
+        (status = OK, description = "The handler is live and reporting in."),
+        (status = SERVICE_UNAVAILABLE, description = "The handler hasn't reported liveness recently."),
     )
 )]
-#[instrument]
-pub async fn health() -> StatusCode {
-    StatusCode::OK
+#[instrument(skip(health))]
+pub async fn health(State(health): State<Option<watch::Receiver<HealthSnapshot>>>) -> StatusCode {
+    match health {
+        Some(rx) if !rx.borrow().is_live(LIVENESS_WINDOW) => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::OK,
+    }
 }
 
-/// Provides all the routes to report status
+/// Provides all the routes to report status. Endpoints that wire a handler's [`HealthReporter`]
+/// through `serve_http` get a `/health` that reflects real liveness; endpoints that don't (e.g.
+/// the handler hasn't been migrated to report health yet) fall back to an always-OK stub.
+///
+/// [`HealthReporter`]: hfendpoints_core::HealthReporter
 #[derive(Default)]
-pub struct StatusRouter;
+pub struct StatusRouter(pub Option<watch::Receiver<HealthSnapshot>>);
 
 /// Convert the underlying `StatusRouter` to one compatible with `utoipa_axum::router::OpenApiRouter`
 impl From<StatusRouter> for OpenApiRouter {
-    fn from(_: StatusRouter) -> Self {
-        OpenApiRouter::new().routes(routes!(health))
+    fn from(value: StatusRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(health))
+            .with_state(value.0)
     }
 }
@@ -1,5 +1,27 @@
-///
-pub enum InferError {}
+//! Native (non-Python) inference backends. `onnx::OnnxEmbeddingModel` and `tokenizer` are composed
+//! by `hfendpoints_openai::embeddings::native::NativeOnnxEmbeddingHandler` into the
+//! `NativeOnnxEmbeddingEndpoint` pyclass, giving them a Python-constructible entry point reachable
+//! from `_hfendpoints` (the workspace's `#[pymodule]` entry point in `hfendpoints/src/lib.rs`).
+
+#[cfg(feature = "onnx")]
+pub mod onnx;
+
+#[cfg(feature = "tokenizers")]
+pub mod tokenizer;
+
+use thiserror::Error;
+
+/// Errors raised by a native (non-Python) inference backend
+#[derive(Debug, Error)]
+pub enum InferError {
+    #[cfg(feature = "onnx")]
+    #[error("ONNX Runtime error: {0}")]
+    Onnx(#[from] ort::Error),
+
+    #[cfg(feature = "tokenizers")]
+    #[error("Tokenizer error: {0}")]
+    Tokenizer(#[from] tokenizers::Error),
+}
 
 ///
 pub type InferResult<T> = Result<T, InferError>;
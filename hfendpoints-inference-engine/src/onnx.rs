@@ -0,0 +1,208 @@
+use crate::InferResult;
+use hfendpoints_core::environ::{EnvironmentError, TryFromEnv};
+use hfendpoints_core::{Error, Handler};
+use hfendpoints_tasks::embedding::{
+    BatchedEmbeddings, EmbeddingHandler, EmbeddingInput, EmbeddingMatrix, EmbeddingRequest,
+    EmbeddingResponse,
+};
+use hfendpoints_tasks::{EndpointResponse, MaybeBatched, Usage};
+use ort::execution_providers::{CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider, ExecutionProviderDispatch};
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::Tensor;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Session-level tuning knobs for the ONNX Runtime backed endpoints, sourced from the
+/// environment so the same container image can be retargeted (CPU/CUDA/CoreML) without a rebuild.
+pub struct OnnxSessionOptions {
+    /// Number of threads the intra-op thread pool is allowed to use
+    pub intra_op_num_threads: usize,
+
+    /// Ordered list of execution providers `ort` should try to register, most-preferred first
+    pub execution_providers: Vec<ExecutionProviderDispatch>,
+}
+
+impl TryFromEnv for OnnxSessionOptions {
+    const ENV_VAR_NAME: &'static str = "HFENDPOINTS_ONNX_INTRA_OP_THREADS";
+
+    fn try_from_env() -> Result<Self, EnvironmentError> {
+        let intra_op_num_threads = match std::env::var(Self::ENV_VAR_NAME) {
+            Ok(value) => value.parse::<usize>().map_err(|e| {
+                EnvironmentError::InvalidEnvVar(Self::ENV_VAR_NAME.into(), e.to_string())
+            })?,
+            Err(_) => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        };
+
+        const EXECUTION_PROVIDERS_ENV_VAR_NAME: &str = "HFENDPOINTS_ONNX_EXECUTION_PROVIDERS";
+        let mut execution_providers = Vec::new();
+        if let Ok(value) = std::env::var(EXECUTION_PROVIDERS_ENV_VAR_NAME) {
+            for provider in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                execution_providers.push(match provider.to_ascii_lowercase().as_str() {
+                    "cuda" => CUDAExecutionProvider::default().build(),
+                    "coreml" => CoreMLExecutionProvider::default().build(),
+                    "cpu" => CPUExecutionProvider::default().build(),
+                    unknown => {
+                        return Err(EnvironmentError::InvalidEnvVar(
+                            EXECUTION_PROVIDERS_ENV_VAR_NAME.into(),
+                            format!("unknown execution provider: {unknown}"),
+                        ))
+                    }
+                });
+            }
+        }
+
+        if execution_providers.is_empty() {
+            execution_providers.push(CPUExecutionProvider::default().build());
+        }
+
+        Ok(Self {
+            intra_op_num_threads,
+            execution_providers,
+        })
+    }
+}
+
+/// Rust-native embedding model backed by an exported ONNX Runtime encoder graph, so embedding
+/// models can be served with no Python in the hot path.
+///
+/// The session is expected to expose the usual `input_ids` / `attention_mask` named inputs and a
+/// `last_hidden_state` named output, as produced by `optimum-cli export onnx` for encoder models.
+pub struct OnnxEmbeddingModel {
+    session: Mutex<Session>,
+}
+
+impl OnnxEmbeddingModel {
+    /// Load an exported encoder graph from `path`, configuring the underlying `ort` session
+    /// according to `options`.
+    pub fn from_file(path: impl AsRef<Path>, options: OnnxSessionOptions) -> InferResult<Self> {
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_intra_threads(options.intra_op_num_threads)?
+            .with_execution_providers(options.execution_providers)?
+            .commit_from_file(path)?;
+
+        Ok(Self {
+            session: Mutex::new(session),
+        })
+    }
+
+    fn input_ids_and_mask(request: &EmbeddingRequest) -> Result<(Vec<Vec<i64>>, Vec<Vec<i64>>), Error> {
+        let inputs = match &request.inputs {
+            MaybeBatched::Single(input) => std::slice::from_ref(input),
+            MaybeBatched::Batch(inputs) => inputs.as_slice(),
+        };
+
+        inputs
+            .iter()
+            .map(|input| match input {
+                EmbeddingInput::Tokens(ids) => {
+                    let ids = ids.iter().map(|id| *id as i64).collect::<Vec<_>>();
+                    let mask = vec![1i64; ids.len()];
+                    Ok((ids, mask))
+                }
+                EmbeddingInput::Text(_) => Err(Error::Runtime(
+                    "OnnxEmbeddingModel expects pre-tokenized EmbeddingInput::Tokens, \
+                     run the request through a tokenization handler first"
+                        .into(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|pairs| pairs.into_iter().unzip())
+    }
+
+    /// Mean-pool `hidden_states` (`num_rows x seq_len x hidden_size`, flattened) over the
+    /// sequence dimension, honoring each row's real (unpadded) length from `seq_lens`.
+    fn mean_pool(hidden_states: &[f32], seq_lens: &[usize], hidden_size: usize) -> Vec<Vec<f32>> {
+        let mut offset = 0;
+        seq_lens
+            .iter()
+            .map(|&seq_len| {
+                let mut pooled = vec![0f32; hidden_size];
+                for _ in 0..seq_len {
+                    for (acc, value) in pooled.iter_mut().zip(&hidden_states[offset..offset + hidden_size]) {
+                        *acc += value;
+                    }
+                    offset += hidden_size;
+                }
+                if seq_len > 0 {
+                    for value in pooled.iter_mut() {
+                        *value /= seq_len as f32;
+                    }
+                }
+                pooled
+            })
+            .collect()
+    }
+}
+
+impl Handler for OnnxEmbeddingModel {
+    type Request = EmbeddingRequest;
+    type Response = EmbeddingResponse;
+
+    fn on_request(
+        &self,
+        request: Self::Request,
+    ) -> impl Future<Output = Result<Self::Response, Error>> + Send {
+        async move {
+            let (input_ids, attention_mask) = Self::input_ids_and_mask(&request)?;
+            let seq_lens = input_ids.iter().map(Vec::len).collect::<Vec<_>>();
+            let max_len = seq_lens.iter().copied().max().unwrap_or(0);
+            let num_rows = input_ids.len();
+
+            let mut padded_ids = vec![0i64; num_rows * max_len];
+            let mut padded_mask = vec![0i64; num_rows * max_len];
+            for (row, (ids, mask)) in input_ids.iter().zip(attention_mask.iter()).enumerate() {
+                let offset = row * max_len;
+                padded_ids[offset..offset + ids.len()].copy_from_slice(ids);
+                padded_mask[offset..offset + mask.len()].copy_from_slice(mask);
+            }
+
+            let input_ids_tensor = Tensor::from_array(([num_rows, max_len], padded_ids))
+                .map_err(|e| Error::Runtime(e.to_string().into()))?;
+            let attention_mask_tensor = Tensor::from_array(([num_rows, max_len], padded_mask))
+                .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+            let hidden_size;
+            let pooled = {
+                let mut session = self
+                    .session
+                    .lock()
+                    .map_err(|_| Error::Runtime("ONNX Runtime session lock was poisoned".into()))?;
+
+                let outputs = session
+                    .run(ort::inputs![
+                        "input_ids" => input_ids_tensor,
+                        "attention_mask" => attention_mask_tensor,
+                    ])
+                    .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+                let (shape, hidden_states) = outputs["last_hidden_state"]
+                    .try_extract_tensor::<f32>()
+                    .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+                hidden_size = *shape.last().unwrap_or(&0) as usize;
+                Self::mean_pool(hidden_states, &seq_lens, hidden_size)
+            };
+
+            let output = match pooled.len() {
+                1 => BatchedEmbeddings::Single(pooled.into_iter().next().unwrap_or_default()),
+                rows => {
+                    let cols = hidden_size;
+                    let data = pooled.into_iter().flatten().collect();
+                    BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(data, rows, cols))
+                }
+            };
+
+            Ok(EndpointResponse {
+                output,
+                usage: Some(Usage::same(seq_lens.iter().sum())),
+            })
+        }
+    }
+}
+
+impl EmbeddingHandler for OnnxEmbeddingModel {
+    type TypedRequest = EmbeddingRequest;
+    type TypedResponse = EmbeddingResponse;
+}
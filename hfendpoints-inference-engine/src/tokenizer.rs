@@ -0,0 +1,162 @@
+//! Server-side tokenization, prompt templating, and truncation for embedding inputs. Composed by
+//! `hfendpoints_openai::embeddings::native::NativeOnnxEmbeddingHandler` alongside `onnx`, giving it
+//! a Python-constructible entry point (`NativeOnnxEmbeddingEndpoint`) reachable from `_hfendpoints`.
+
+use crate::InferResult;
+use hfendpoints_core::environ::{EnvironmentError, TryFromEnv};
+use hfendpoints_tasks::embedding::{EmbeddingInput, EmbeddingParams, TruncationDirection};
+use hfendpoints_tasks::{MaybeBatched, Usage};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokenizers::{Tokenizer, TruncationDirection as HfTruncationDirection, TruncationParams, TruncationStrategy};
+
+/// Location of the `tokenizer.json` the embedding tokenization subsystem should load, and the
+/// model's maximum sequence length, sourced from the environment so the same container image can
+/// be retargeted to a different model without a rebuild.
+pub struct TokenizerOptions {
+    /// Path to a `tokenizer.json` exported by the `tokenizers` library
+    pub tokenizer_path: PathBuf,
+
+    /// Maximum number of tokens the underlying model accepts
+    pub max_length: usize,
+}
+
+impl TryFromEnv for TokenizerOptions {
+    const ENV_VAR_NAME: &'static str = "HFENDPOINTS_TOKENIZER_PATH";
+
+    fn try_from_env() -> Result<Self, EnvironmentError> {
+        let tokenizer_path = std::env::var(Self::ENV_VAR_NAME)
+            .map_err(|_| EnvironmentError::MissingEnvVar(Self::ENV_VAR_NAME.to_string()))?
+            .into();
+
+        const MAX_LENGTH_ENV_VAR_NAME: &str = "HFENDPOINTS_TOKENIZER_MAX_LENGTH";
+        let max_length = match std::env::var(MAX_LENGTH_ENV_VAR_NAME) {
+            Ok(value) => value.parse::<usize>().map_err(|e| {
+                EnvironmentError::InvalidEnvVar(MAX_LENGTH_ENV_VAR_NAME.into(), e.to_string())
+            })?,
+            Err(_) => 512,
+        };
+
+        Ok(Self {
+            tokenizer_path,
+            max_length,
+        })
+    }
+}
+
+/// Turns the declarative knobs on `EmbeddingParams` (`prompt_name`, `truncate`,
+/// `truncation_direction`) into actual preprocessing: prompt templating and tokenization,
+/// applied to a request's input before it reaches a `Handler`.
+///
+/// Loaded from a model's `tokenizer.json` via the Hugging Face `tokenizers` crate, so embedding
+/// models can be tokenized without going through Python.
+pub struct EmbeddingTokenizer {
+    tokenizer: Tokenizer,
+
+    /// Named prompt templates, keyed the same way as a `sentence-transformers` `prompts` config
+    prompts: HashMap<String, String>,
+
+    max_length: usize,
+}
+
+impl EmbeddingTokenizer {
+    /// Load `tokenizer.json` from `options.tokenizer_path`, pairing it with the `prompts` map a
+    /// model's `sentence-transformers` configuration declares (pass an empty map if the model
+    /// defines none).
+    pub fn from_options(
+        options: TokenizerOptions,
+        prompts: HashMap<String, String>,
+    ) -> InferResult<Self> {
+        let tokenizer = Tokenizer::from_file(&options.tokenizer_path)?;
+
+        Ok(Self {
+            tokenizer,
+            prompts,
+            max_length: options.max_length,
+        })
+    }
+
+    /// Resolve `params.prompt_name` against the loaded `prompts` map, prepending the template to
+    /// `text`. Falls back to `text` unchanged when `prompt_name` is unset or unknown.
+    fn apply_prompt(&self, text: &str, params: &EmbeddingParams) -> String {
+        match params.prompt_name.as_deref().and_then(|name| self.prompts.get(name)) {
+            Some(prompt) => format!("{prompt}{text}"),
+            None => text.to_string(),
+        }
+    }
+
+    fn truncation_params(&self, params: &EmbeddingParams) -> Option<TruncationParams> {
+        if params.truncate != Some(true) {
+            return None;
+        }
+
+        let direction = match params.truncation_direction {
+            Some(TruncationDirection::Left) => HfTruncationDirection::Left,
+            _ => HfTruncationDirection::Right,
+        };
+
+        Some(TruncationParams {
+            max_length: self.max_length,
+            direction,
+            strategy: TruncationStrategy::LongestFirst,
+            stride: 0,
+        })
+    }
+
+    /// Tokenize a single `EmbeddingInput`, applying the prompt template and truncation requested
+    /// by `params`. Already-tokenized inputs (`EmbeddingInput::Tokens`) pass through untouched,
+    /// as the caller is assumed to have performed this step already.
+    fn prepare_one(
+        &self,
+        input: EmbeddingInput,
+        params: &EmbeddingParams,
+    ) -> InferResult<(EmbeddingInput, usize)> {
+        match input {
+            EmbeddingInput::Tokens(ids) => {
+                let len = ids.len();
+                Ok((EmbeddingInput::Tokens(ids), len))
+            }
+            EmbeddingInput::Text(text) => {
+                let text = self.apply_prompt(&text, params);
+
+                let mut tokenizer = self.tokenizer.clone();
+                if let Some(truncation) = self.truncation_params(params) {
+                    tokenizer.with_truncation(Some(truncation))?;
+                }
+
+                let encoding = tokenizer.encode(text, true)?;
+                let ids = encoding.get_ids().to_vec();
+                let num_tokens = ids.len();
+
+                Ok((EmbeddingInput::Tokens(ids), num_tokens))
+            }
+        }
+    }
+
+    /// Tokenize every input carried by `inputs`, returning the tokenized inputs alongside the
+    /// `Usage` to attach to the eventual response.
+    pub fn prepare(
+        &self,
+        inputs: MaybeBatched<EmbeddingInput>,
+        params: &EmbeddingParams,
+    ) -> InferResult<(MaybeBatched<EmbeddingInput>, Usage)> {
+        match inputs {
+            MaybeBatched::Single(input) => {
+                let (input, num_tokens) = self.prepare_one(input, params)?;
+                Ok((MaybeBatched::Single(input), Usage::same(num_tokens)))
+            }
+            MaybeBatched::Batch(inputs) => {
+                let mut prepared = Vec::with_capacity(inputs.len());
+                let mut total_tokens = 0;
+
+                for input in inputs {
+                    let (input, num_tokens) = self.prepare_one(input, params)?;
+                    total_tokens += num_tokens;
+                    prepared.push(input);
+                }
+
+                Ok((MaybeBatched::Batch(prepared), Usage::same(total_tokens)))
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::embeddings::HuggingFaceInferenceEmbeddingResponse;
+use axum::extract::{Multipart, State};
+use axum_extra::TypedHeader;
+use hfendpoints_core::{EndpointContext, EndpointResult, Error};
+use hfendpoints_http::headers::RequestId;
+use hfendpoints_http::{Context, HttpError, HttpResult, RequestWithContext, EMBEDDINGS_TAG};
+use hfendpoints_tasks::embedding::{EmbeddingInput, EmbeddingParams, EmbeddingRequest};
+use hfendpoints_tasks::MaybeBatched;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::instrument;
+use tracing::log::info;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// Swagger/OpenAPI-only description of the `multipart/form-data` body [`AudioEmbeddingRequest`]
+/// is parsed from.
+#[derive(ToSchema)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct AudioEmbeddingForm {
+    /// The audio clip to embed into a fixed-length speaker/voiceprint vector, in one of these
+    /// formats: flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm.
+    #[schema(format = Binary)]
+    file: String,
+}
+
+/// A request to embed a single uploaded audio clip (e.g. a speaker enrollment or test clip) into
+/// a fixed-length vector, for speaker-verification or audio-search use cases.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone)]
+pub struct AudioEmbeddingRequest {
+    pub file: Vec<u8>,
+}
+
+impl AudioEmbeddingRequest {
+    async fn try_from_multipart(mut multipart: Multipart) -> HttpResult<Self> {
+        let mut file: Option<Vec<u8>> = None;
+
+        while let Some(field) = multipart.next_field().await? {
+            let name = field
+                .name()
+                .ok_or_else(|| HttpError::Validation("Unnamed multipart field".to_string()))?
+                .to_string();
+
+            match name.as_str() {
+                "file" => file = Some(field.bytes().await?.to_vec()),
+                _ => return Err(HttpError::Validation(format!("Unknown field: {name}"))),
+            }
+        }
+
+        let file = file.ok_or_else(|| {
+            HttpError::Validation("Required parameter 'file' was not provided".to_string())
+        })?;
+
+        Ok(Self { file })
+    }
+}
+
+type AudioEmbeddingRequestWithContext = RequestWithContext<AudioEmbeddingRequest>;
+
+impl TryFrom<AudioEmbeddingRequest> for EmbeddingRequest {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: AudioEmbeddingRequest) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            MaybeBatched::Single(EmbeddingInput::Audio(value.file)),
+            EmbeddingParams::default(),
+        ))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/embeddings/audio",
+    tag = EMBEDDINGS_TAG,
+    request_body(content = AudioEmbeddingForm, content_type = "multipart/form-data"),
+    responses(
+        (status = OK, description = "Creates a speaker/voiceprint embedding vector for the uploaded audio clip.", body = HuggingFaceInferenceEmbeddingResponse),
+    )
+)]
+#[instrument(skip(state))]
+async fn embed_audio(
+    State(state): State<
+        EndpointContext<AudioEmbeddingRequestWithContext, HuggingFaceInferenceEmbeddingResponse>,
+    >,
+    request_id: TypedHeader<RequestId>,
+    multipart: Multipart,
+) -> HttpResult<HuggingFaceInferenceEmbeddingResponse> {
+    let request = AudioEmbeddingRequest::try_from_multipart(multipart).await?;
+    info!(
+        "Received audio clip ({} kB) for embedding",
+        request.file.len() / 1024
+    );
+
+    let ctx = Context::new(request_id.0);
+    let mut egress = state.schedule((request, ctx))?;
+    if let Some(response) = egress.recv().await {
+        Ok(response?)
+    } else {
+        Err(HttpError::NoResponse)
+    }
+}
+
+/// Helper factory to build the speaker/audio embedding endpoint.
+#[derive(Clone)]
+pub struct AudioEmbeddingRouter(
+    pub  UnboundedSender<(
+        AudioEmbeddingRequestWithContext,
+        UnboundedSender<EndpointResult<HuggingFaceInferenceEmbeddingResponse>>,
+    )>,
+);
+
+impl From<AudioEmbeddingRouter> for OpenApiRouter {
+    fn from(value: AudioEmbeddingRouter) -> Self {
+        OpenApiRouter::new()
+            .routes(routes!(embed_audio))
+            .with_state(EndpointContext::new(value.0))
+    }
+}
+
+#[cfg(feature = "python")]
+pub mod python {
+    use crate::embeddings::audio::{AudioEmbeddingRequest, AudioEmbeddingRouter};
+    use crate::embeddings::HuggingFaceInferenceEmbeddingResponse;
+    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use hfendpoints_http::{impl_http_pyendpoint, impl_http_pyhandler};
+    use hfendpoints_tasks::embedding::python::{PyEmbeddingRequest, PyEmbeddingResponse};
+    use pyo3::prelude::*;
+
+    impl_http_pyhandler!(
+        AudioEmbeddingRequest,
+        HuggingFaceInferenceEmbeddingResponse,
+        PyEmbeddingRequest,
+        PyEmbeddingResponse
+    );
+
+    impl_http_pyendpoint!(
+        "AudioEmbeddingEndpoint",
+        PyAudioEmbeddingEndpoint,
+        PyHandler,
+        AudioEmbeddingRouter
+    );
+
+    pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
+        let module = ImportablePyModuleBuilder::new(py, name)?
+            .defaults()?
+            .add_class::<PyAudioEmbeddingEndpoint>()?
+            .finish();
+
+        Ok(module)
+    }
+}
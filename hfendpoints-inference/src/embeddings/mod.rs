@@ -2,20 +2,37 @@ use axum::extract::State;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use axum_extra::TypedHeader;
-use hfendpoints_core::{EndpointContext, EndpointResult, Error};
+use base64::Engine;
+use hfendpoints_core::{Batcher, EndpointContext, EndpointResult, Error};
 use hfendpoints_http::headers::RequestId;
 use hfendpoints_http::{Context, HttpError, HttpResult, RequestWithContext, EMBEDDINGS_TAG};
 use hfendpoints_tasks::embedding::{
-    EmbeddingInput, EmbeddingParams, EmbeddingRequest, EmbeddingResponse,
+    BatchedEmbeddings, EmbeddingInput, EmbeddingMatrix, EmbeddingParams, EmbeddingRequest,
+    EmbeddingResponse,
 };
-use hfendpoints_tasks::MaybeBatched;
+use hfendpoints_tasks::{MaybeBatched, Usage};
 use serde::{Deserialize, Serialize};
+use std::mem::size_of;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::instrument;
 use utoipa::ToSchema;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+pub(crate) mod audio;
+
+/// The wire format the returned embedding vectors should be encoded in: raw `float` arrays (the
+/// default), or each vector packed as 4-byte little-endian `f32`s and base64-encoded, trading the
+/// JSON float-array overhead for a smaller response body.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EncodingFormat {
+    #[default]
+    Float,
+    Base64,
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[derive(Clone, Deserialize, Serialize, ToSchema)]
 pub struct HuggingFaceInferenceEmbeddingRequest {
@@ -24,12 +41,152 @@ pub struct HuggingFaceInferenceEmbeddingRequest {
 
     #[serde(flatten)]
     parameters: EmbeddingParams,
+
+    /// The wire format the returned embedding vectors should be encoded in; see
+    /// [`EncodingFormat`].
+    #[serde(default)]
+    encoding_format: EncodingFormat,
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
-#[cfg_attr(test, derive(Deserialize))]
 #[derive(Clone, Serialize, ToSchema)]
-pub struct HuggingFaceInferenceEmbeddingResponse(MaybeBatched<Vec<f32>>);
+pub struct HuggingFaceInferenceEmbeddingResponse {
+    embeddings: BatchedEmbeddings,
+
+    /// Token accounting for this call. Populated from the handler's own count when it supplies
+    /// one; otherwise falls back to tokenizing the request's inputs with the same tokenizer used
+    /// by the OpenAI-compatible route's preflight pass.
+    usage: Usage,
+}
+
+impl HuggingFaceInferenceEmbeddingResponse {
+    /// Applies the Matryoshka truncation and L2 normalization requested through `dimension`/
+    /// `normalize` to every vector carried by this response, guarding against a zero norm.
+    /// Handlers are free to honor `EmbeddingParams` themselves since it's forwarded into
+    /// `EmbeddingRequest` untouched, but this is applied here too so the parameters are honored
+    /// end-to-end even when a handler ignores them -- truncating/renormalizing an already
+    /// truncated/unit vector is a no-op, so applying it twice is harmless.
+    ///
+    /// Truncation is applied before normalization, so a truncated vector is renormalized to unit
+    /// length rather than inheriting the norm of the full untruncated vector.
+    fn postprocess(mut self, dimension: Option<usize>, normalize: Option<bool>) -> HttpResult<Self> {
+        if let Some(dimension) = dimension {
+            if dimension == 0 {
+                return Err(HttpError::Validation(
+                    "requested embedding dimension must be greater than zero".into(),
+                ));
+            }
+
+            self.embeddings = match self.embeddings {
+                BatchedEmbeddings::Single(mut embedding) => {
+                    Self::check_dimension(dimension, embedding.len())?;
+                    embedding.truncate(dimension);
+                    BatchedEmbeddings::Single(embedding)
+                }
+                BatchedEmbeddings::Batch(matrix) => {
+                    Self::check_dimension(dimension, matrix.cols())?;
+                    BatchedEmbeddings::Batch(matrix.truncate_cols(dimension))
+                }
+            };
+        }
+
+        if normalize == Some(true) {
+            match &mut self.embeddings {
+                BatchedEmbeddings::Single(embedding) => Self::normalize_vector(embedding),
+                BatchedEmbeddings::Batch(matrix) => {
+                    for row in matrix.rows_mut() {
+                        Self::normalize_vector(row);
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Replaces `usage` with `prompt_tokens` (as both prompt and total token counts) unless the
+    /// handler already reported a non-zero count of its own.
+    fn fill_usage_if_missing(mut self, prompt_tokens: usize) -> Self {
+        if self.usage.prompt_tokens == 0 && self.usage.total_tokens == 0 {
+            self.usage = Usage::same(prompt_tokens);
+        }
+        self
+    }
+
+    fn check_dimension(dimension: usize, actual: usize) -> HttpResult<()> {
+        if dimension > actual {
+            return Err(HttpError::Validation(format!(
+                "requested embedding dimension {dimension} exceeds the model's output dimension {actual}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn normalize_vector(embedding: &mut [f32]) {
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for x in embedding.iter_mut() {
+                *x /= norm;
+            }
+        }
+    }
+}
+
+/// A batch of embeddings base64-encoded per [`EncodingFormat::Base64`]: each vector's `f32`s
+/// packed little-endian and base64-encoded into a single string.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum Base64Embeddings {
+    Single(String),
+    Batch(Vec<String>),
+}
+
+fn encode_base64(embeddings: &BatchedEmbeddings) -> Base64Embeddings {
+    match embeddings {
+        BatchedEmbeddings::Single(values) => Base64Embeddings::Single(encode_vector(values)),
+        BatchedEmbeddings::Batch(matrix) => Base64Embeddings::Batch(
+            (0..matrix.rows())
+                .map(|index| encode_vector(matrix.row(index)))
+                .collect(),
+        ),
+    }
+}
+
+fn encode_vector(values: &[f32]) -> String {
+    let mut bytes = Vec::with_capacity(values.len() * size_of::<f32>());
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+/// Either the plain float vectors, or the same vectors re-encoded as base64 when the request's
+/// `encoding_format` asked for it.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum EncodedEmbeddingVectors {
+    Float(BatchedEmbeddings),
+    Base64(Base64Embeddings),
+}
+
+/// Wire body for the `/embeddings` route: the embedding vectors alongside the token usage
+/// accounting for the call.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct EncodedEmbeddings {
+    embeddings: EncodedEmbeddingVectors,
+    usage: Usage,
+}
+
+impl IntoResponse for EncodedEmbeddings {
+    fn into_response(self) -> Response {
+        Json::from(self).into_response()
+    }
+}
 
 type HuggingFaceInferenceEmbeddingRequestWithContext =
     RequestWithContext<HuggingFaceInferenceEmbeddingRequest>;
@@ -48,7 +205,10 @@ impl TryFrom<EmbeddingResponse> for HuggingFaceInferenceEmbeddingResponse {
 
     #[inline]
     fn try_from(value: EmbeddingResponse) -> Result<Self, Self::Error> {
-        Ok(Self(value.output))
+        Ok(Self {
+            embeddings: value.output,
+            usage: value.usage.unwrap_or_default(),
+        })
     }
 }
 
@@ -64,7 +224,7 @@ impl IntoResponse for HuggingFaceInferenceEmbeddingResponse {
     tag = EMBEDDINGS_TAG,
     request_body(content = HuggingFaceInferenceEmbeddingRequest, content_type = "application/json"),
     responses(
-        (status = OK, description = "Creates an embedding vector representing the input text.", body = HuggingFaceInferenceEmbeddingResponse),
+        (status = OK, description = "Creates an embedding vector representing the input text.", body = EncodedEmbeddings),
     )
 )]
 #[instrument(skip(state, request))]
@@ -77,17 +237,43 @@ async fn embed(
     >,
     request_id: TypedHeader<RequestId>,
     Json(request): Json<HuggingFaceInferenceEmbeddingRequest>,
-) -> HttpResult<HuggingFaceInferenceEmbeddingResponse> {
+) -> HttpResult<EncodedEmbeddings> {
     let ctx = Context::new(request_id.0);
+    let normalize = request.parameters.normalize;
+    let dimension = request.parameters.dimension;
+    let encoding_format = request.encoding_format;
+    let prompt_tokens = count_prompt_tokens(&request.inputs);
 
     let mut egress = state.schedule((request, ctx))?;
     if let Some(response) = egress.recv().await {
-        Ok(response?)
+        let response = response?
+            .postprocess(dimension, normalize)?
+            .fill_usage_if_missing(prompt_tokens);
+        let usage = response.usage;
+        Ok(EncodedEmbeddings {
+            embeddings: match encoding_format {
+                EncodingFormat::Float => EncodedEmbeddingVectors::Float(response.embeddings),
+                EncodingFormat::Base64 => {
+                    EncodedEmbeddingVectors::Base64(encode_base64(&response.embeddings))
+                }
+            },
+            usage,
+        })
     } else {
         Err(HttpError::NoResponse)
     }
 }
 
+/// Counts prompt tokens for usage accounting by tokenizing every input with the same tokenizer the
+/// OpenAI-compatible route uses for its preflight pass -- used as a fallback when the handler
+/// doesn't report its own token count.
+fn count_prompt_tokens(inputs: &MaybeBatched<EmbeddingInput>) -> usize {
+    match inputs {
+        MaybeBatched::Single(input) => openai::tokenizer::count_tokens(input),
+        MaybeBatched::Batched(items) => items.iter().map(openai::tokenizer::count_tokens).sum(),
+    }
+}
+
 #[derive(Clone)]
 pub struct HuggingFaceInferenceEmbeddingRouter(
     pub  UnboundedSender<(
@@ -104,17 +290,161 @@ impl From<HuggingFaceInferenceEmbeddingRouter> for OpenApiRouter {
     }
 }
 
+/// Coalesces concurrently-arriving single-input embedding requests into one batched
+/// `HuggingFaceInferenceEmbeddingRequest` before it reaches the handler, amortizing model
+/// invocation overhead the way chunk-level batch embedding does in indexing pipelines.
+/// [`compatible`](Batcher::compatible) keeps requests whose `parameters`/`encoding_format` differ
+/// (e.g. asymmetric retrieval's "query:" vs "passage:" `prompt_name`) out of the same merged call,
+/// since those affect what's actually fed to the model rather than just response shaping --
+/// `batch_requests` dispatches each such group as its own separate handler call instead.
+pub(crate) struct EmbeddingBatcher;
+
+impl Batcher<HuggingFaceInferenceEmbeddingRequestWithContext, HuggingFaceInferenceEmbeddingResponse>
+    for EmbeddingBatcher
+{
+    fn compatible(
+        &self,
+        a: &HuggingFaceInferenceEmbeddingRequestWithContext,
+        b: &HuggingFaceInferenceEmbeddingRequestWithContext,
+    ) -> bool {
+        Self::requests_compatible(&a.0, &b.0)
+    }
+
+    fn merge(
+        &self,
+        requests: Vec<HuggingFaceInferenceEmbeddingRequestWithContext>,
+    ) -> (HuggingFaceInferenceEmbeddingRequestWithContext, Vec<usize>) {
+        let mut bodies = Vec::with_capacity(requests.len());
+        let mut contexts = Vec::with_capacity(requests.len());
+        for (request, context) in requests {
+            bodies.push(request);
+            contexts.push(context);
+        }
+
+        let (merged, group_sizes) = Self::merge_requests(bodies);
+        let context = contexts
+            .into_iter()
+            .next()
+            .expect("merge is always called with at least one request");
+
+        ((merged, context), group_sizes)
+    }
+
+    fn split(
+        &self,
+        response: HuggingFaceInferenceEmbeddingResponse,
+        group_sizes: Vec<usize>,
+    ) -> Vec<HuggingFaceInferenceEmbeddingResponse> {
+        self.split_responses(response, group_sizes)
+    }
+}
+
+impl EmbeddingBatcher {
+    /// The `Context`-free core of [`Batcher::compatible`]: two requests may only be coalesced
+    /// into the same [`merge_requests`](Self::merge_requests) call if they agree on every setting
+    /// that affects what's actually fed to the model, not just response shaping. Split out from
+    /// the trait method so it can be unit-tested without having to construct a `Context`.
+    fn requests_compatible(
+        a: &HuggingFaceInferenceEmbeddingRequest,
+        b: &HuggingFaceInferenceEmbeddingRequest,
+    ) -> bool {
+        a.parameters == b.parameters && a.encoding_format == b.encoding_format
+    }
+
+    /// The `Context`-free core of [`Batcher::merge`]: flattens every request's inputs into one
+    /// batch and records how many rows each original request contributed, so the combined
+    /// response can later be carved back apart by [`split_responses`](Self::split_responses).
+    /// `batch_requests` only ever calls [`Batcher::merge`] with requests [`Batcher::compatible`]
+    /// deemed mutually compatible, so every request here is guaranteed to share the same
+    /// `parameters`/`encoding_format` -- taking them from the first request is safe, not a
+    /// best-effort fallback. Split out from the trait method so it can be unit-tested without
+    /// having to construct a `Context`.
+    fn merge_requests(
+        requests: Vec<HuggingFaceInferenceEmbeddingRequest>,
+    ) -> (HuggingFaceInferenceEmbeddingRequest, Vec<usize>) {
+        let mut group_sizes = Vec::with_capacity(requests.len());
+        let mut flattened = Vec::new();
+        let mut leader = None;
+
+        for request in requests {
+            let inputs = match request.inputs {
+                MaybeBatched::Single(input) => vec![input],
+                MaybeBatched::Batched(items) => items,
+            };
+            group_sizes.push(inputs.len());
+            flattened.extend(inputs);
+
+            leader.get_or_insert((request.parameters, request.encoding_format));
+        }
+
+        let (parameters, encoding_format) =
+            leader.expect("merge_requests is always called with at least one request");
+
+        let inputs = if flattened.len() == 1 {
+            MaybeBatched::Single(flattened.into_iter().next().unwrap())
+        } else {
+            MaybeBatched::Batched(flattened)
+        };
+
+        let merged = HuggingFaceInferenceEmbeddingRequest {
+            inputs,
+            parameters,
+            encoding_format,
+        };
+
+        (merged, group_sizes)
+    }
+
+    /// Splits the merged batch's response back into one response per original caller.
+    ///
+    /// Usage isn't tracked per sub-request here -- the merged request's token count can't be
+    /// attributed back to each original caller without re-tokenizing -- so every split response
+    /// reports zero usage; real token accounting for a batched call should come from the handler
+    /// counting each physical request it actually received.
+    fn split_responses(
+        &self,
+        response: HuggingFaceInferenceEmbeddingResponse,
+        group_sizes: Vec<usize>,
+    ) -> Vec<HuggingFaceInferenceEmbeddingResponse> {
+        let rows = match response.embeddings {
+            BatchedEmbeddings::Single(embedding) => vec![embedding],
+            BatchedEmbeddings::Batch(matrix) => matrix.to_nested_vec(),
+        };
+        let mut rows = rows.into_iter();
+
+        group_sizes
+            .into_iter()
+            .map(|size| {
+                let group: Vec<Vec<f32>> = (0..size).filter_map(|_| rows.next()).collect();
+                let embeddings = if group.len() == 1 {
+                    BatchedEmbeddings::Single(group.into_iter().next().unwrap())
+                } else {
+                    let cols = group.first().map_or(0, Vec::len);
+                    let rows_count = group.len();
+                    let flat = group.into_iter().flatten().collect();
+                    BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(flat, rows_count, cols))
+                };
+                HuggingFaceInferenceEmbeddingResponse {
+                    embeddings,
+                    usage: Usage::default(),
+                }
+            })
+            .collect()
+    }
+}
+
 #[cfg(feature = "python")]
 pub mod python {
     use crate::embeddings::{
-        HuggingFaceInferenceEmbeddingRequest, HuggingFaceInferenceEmbeddingResponse,
+        EmbeddingBatcher, HuggingFaceInferenceEmbeddingRequest, HuggingFaceInferenceEmbeddingResponse,
         HuggingFaceInferenceEmbeddingRouter,
     };
     use hfendpoints_binding_python::ImportablePyModuleBuilder;
-    use hfendpoints_http::{impl_http_pyendpoint, impl_http_pyhandler};
+    use hfendpoints_http::{impl_http_pyendpoint_batched, impl_http_pyhandler};
     use hfendpoints_tasks::embedding::python::{PyEmbeddingRequest, PyEmbeddingResponse};
     use pyo3::prelude::PyModule;
     use pyo3::{Bound, PyResult, Python};
+    use std::sync::Arc;
 
     impl_http_pyhandler!(
         HuggingFaceInferenceEmbeddingRequest,
@@ -123,11 +453,12 @@ pub mod python {
         PyEmbeddingResponse
     );
 
-    impl_http_pyendpoint!(
+    impl_http_pyendpoint_batched!(
         "EmbeddingEndpoint",
         PyEmbeddingEndpoint,
         PyHandler,
-        HuggingFaceInferenceEmbeddingRouter
+        HuggingFaceInferenceEmbeddingRouter,
+        Arc::new(EmbeddingBatcher)
     );
 
     pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
@@ -149,6 +480,7 @@ mod tests {
         let request = HuggingFaceInferenceEmbeddingRequest {
             inputs: EmbeddingInput::Text("test text".to_owned()).into(),
             parameters: EmbeddingParams::default(),
+            encoding_format: EncodingFormat::default(),
         };
 
         let converted: EmbeddingRequest = request.try_into().unwrap();
@@ -162,12 +494,14 @@ mod tests {
     fn test_embedding_response_conversion() {
         let embeddings = vec![0.1, 0.2, 0.3];
         let response = EmbeddingResponse {
-            output: MaybeBatched::Single(embeddings.clone()),
+            output: BatchedEmbeddings::Single(embeddings.clone()),
             usage: None,
         };
 
         let converted: HuggingFaceInferenceEmbeddingResponse = response.try_into().unwrap();
-        assert_eq!(converted.0, MaybeBatched::Single(embeddings));
+        assert_eq!(converted.embeddings, BatchedEmbeddings::Single(embeddings));
+        assert_eq!(converted.usage.prompt_tokens, 0);
+        assert_eq!(converted.usage.total_tokens, 0);
     }
 
     #[test]
@@ -179,6 +513,7 @@ mod tests {
         let request = HuggingFaceInferenceEmbeddingRequest {
             inputs: inputs.clone().into(),
             parameters: EmbeddingParams::default(),
+            encoding_format: EncodingFormat::default(),
         };
 
         let converted: EmbeddingRequest = request.try_into().unwrap();
@@ -187,13 +522,262 @@ mod tests {
 
     #[test]
     fn test_batched_embedding_response_conversion() {
-        let embeddings = vec![vec![0.1, 0.2], vec![0.3, 0.4]];
+        let matrix = EmbeddingMatrix::from_flat(vec![0.1, 0.2, 0.3, 0.4], 2, 2);
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Batch(matrix.clone()),
+            usage: None,
+        };
+
+        let converted: HuggingFaceInferenceEmbeddingResponse = response.try_into().unwrap();
+        assert_eq!(converted.embeddings, BatchedEmbeddings::Batch(matrix));
+    }
+
+    #[test]
+    fn test_batched_embedding_response_conversion_falls_back_to_zero_usage() {
         let response = EmbeddingResponse {
-            output: MaybeBatched::Batch(embeddings.clone()),
+            output: BatchedEmbeddings::Single(vec![0.1]),
             usage: None,
         };
 
         let converted: HuggingFaceInferenceEmbeddingResponse = response.try_into().unwrap();
-        assert_eq!(converted.0, MaybeBatched::Batch(embeddings));
+        assert_eq!(converted.usage.prompt_tokens, 0);
+        assert_eq!(converted.usage.total_tokens, 0);
+    }
+
+    fn response_with(embeddings: BatchedEmbeddings) -> HuggingFaceInferenceEmbeddingResponse {
+        HuggingFaceInferenceEmbeddingResponse {
+            embeddings,
+            usage: Usage::default(),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_is_a_no_op_when_not_requested() {
+        let response = response_with(BatchedEmbeddings::Single(vec![3.0, 4.0]));
+
+        let processed = response.postprocess(None, None).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![3.0, 4.0]),
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_normalizes_single_embedding_to_unit_length() {
+        let response = response_with(BatchedEmbeddings::Single(vec![3.0, 4.0]));
+
+        let processed = response.postprocess(None, Some(true)).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![0.6, 0.8]),
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_normalize_leaves_zero_vector_untouched() {
+        let response = response_with(BatchedEmbeddings::Single(vec![0.0, 0.0]));
+
+        let processed = response.postprocess(None, Some(true)).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![0.0, 0.0]),
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_normalizes_every_row_of_a_batch() {
+        let response = response_with(BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(
+            vec![3.0, 4.0, 1.0, 0.0],
+            2,
+            2,
+        )));
+
+        let processed = response.postprocess(None, Some(true)).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Batch(matrix) => {
+                assert_eq!(matrix.row(0), &[0.6, 0.8]);
+                assert_eq!(matrix.row(1), &[1.0, 0.0]);
+            }
+            _ => panic!("Expected Batch variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_truncates_single_embedding_before_normalizing() {
+        let response = response_with(BatchedEmbeddings::Single(vec![3.0, 4.0, 5.0]));
+
+        let processed = response.postprocess(Some(2), Some(true)).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![0.6, 0.8]),
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_truncates_every_row_of_a_batch() {
+        let response = response_with(BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(
+            vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6],
+            2,
+            3,
+        )));
+
+        let processed = response.postprocess(Some(2), None).unwrap();
+        match processed.embeddings {
+            BatchedEmbeddings::Batch(matrix) => {
+                assert_eq!(matrix.cols(), 2);
+                assert_eq!(matrix.row(0), &[0.1, 0.2]);
+                assert_eq!(matrix.row(1), &[0.4, 0.5]);
+            }
+            _ => panic!("Expected Batch variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_rejects_zero_dimension() {
+        let response = response_with(BatchedEmbeddings::Single(vec![1.0, 2.0]));
+
+        assert!(response.postprocess(Some(0), None).is_err());
+    }
+
+    #[test]
+    fn test_postprocess_rejects_dimension_too_large() {
+        let response = response_with(BatchedEmbeddings::Single(vec![1.0, 2.0]));
+
+        assert!(response.postprocess(Some(8), None).is_err());
+    }
+
+    #[test]
+    fn test_fill_usage_if_missing_keeps_handler_reported_usage() {
+        let response = HuggingFaceInferenceEmbeddingResponse {
+            embeddings: BatchedEmbeddings::Single(vec![1.0, 2.0]),
+            usage: Usage::same(5),
+        };
+
+        let filled = response.fill_usage_if_missing(99);
+        assert_eq!(filled.usage.prompt_tokens, 5);
+        assert_eq!(filled.usage.total_tokens, 5);
+    }
+
+    #[test]
+    fn test_fill_usage_if_missing_falls_back_when_handler_reports_zero() {
+        let response = response_with(BatchedEmbeddings::Single(vec![1.0, 2.0]));
+
+        let filled = response.fill_usage_if_missing(7);
+        assert_eq!(filled.usage.prompt_tokens, 7);
+        assert_eq!(filled.usage.total_tokens, 7);
+    }
+
+    #[test]
+    fn test_encode_base64_packs_little_endian_f32_per_vector() {
+        let encoded = encode_base64(&BatchedEmbeddings::Single(vec![0.1, 0.2]));
+        let Base64Embeddings::Single(b64) = encoded else {
+            panic!("Expected Single variant");
+        };
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .unwrap();
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&0.1f32.to_le_bytes());
+        expected.extend_from_slice(&0.2f32.to_le_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_encode_base64_encodes_each_row_of_a_batch() {
+        let matrix = EmbeddingMatrix::from_flat(vec![0.1, 0.2, 0.3, 0.4], 2, 2);
+        let encoded = encode_base64(&BatchedEmbeddings::Batch(matrix));
+        let Base64Embeddings::Batch(rows) = encoded else {
+            panic!("Expected Batch variant");
+        };
+
+        assert_eq!(rows.len(), 2);
+    }
+
+    fn request_with(inputs: Vec<&str>) -> HuggingFaceInferenceEmbeddingRequest {
+        let inputs = inputs
+            .into_iter()
+            .map(|text| EmbeddingInput::Text(text.to_owned()))
+            .collect::<Vec<_>>();
+        let inputs = if inputs.len() == 1 {
+            MaybeBatched::Single(inputs.into_iter().next().unwrap())
+        } else {
+            MaybeBatched::Batched(inputs)
+        };
+
+        HuggingFaceInferenceEmbeddingRequest {
+            inputs,
+            parameters: EmbeddingParams::default(),
+            encoding_format: EncodingFormat::default(),
+        }
+    }
+
+    #[test]
+    fn test_embedding_batcher_merges_single_input_requests_into_one_batch() {
+        let (request, group_sizes) =
+            EmbeddingBatcher::merge_requests(vec![request_with(vec!["a"]), request_with(vec!["b"])]);
+
+        assert_eq!(group_sizes, vec![1, 1]);
+        assert_eq!(
+            request.inputs,
+            MaybeBatched::Batched(vec![
+                EmbeddingInput::Text("a".into()),
+                EmbeddingInput::Text("b".into())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_embedding_batcher_rejects_requests_with_different_prompt_name_as_compatible() {
+        let query = request_with(vec!["a"]);
+        let mut passage = query.clone();
+        passage.parameters.prompt_name = Some("passage".into());
+
+        assert!(!EmbeddingBatcher::requests_compatible(&query, &passage));
+    }
+
+    #[test]
+    fn test_embedding_batcher_accepts_requests_with_identical_parameters_as_compatible() {
+        let a = request_with(vec!["a"]);
+        let b = request_with(vec!["b"]);
+
+        assert!(EmbeddingBatcher::requests_compatible(&a, &b));
+    }
+
+    #[test]
+    fn test_embedding_batcher_merge_preserves_per_request_group_sizes() {
+        let (_, group_sizes) = EmbeddingBatcher::merge_requests(vec![
+            request_with(vec!["a", "b"]),
+            request_with(vec!["c"]),
+        ]);
+
+        assert_eq!(group_sizes, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_embedding_batcher_split_regroups_rows_per_original_request() {
+        let response = response_with(BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(
+            vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6],
+            3,
+            2,
+        )));
+
+        let mut split = EmbeddingBatcher.split_responses(response, vec![2, 1]);
+        assert_eq!(split.len(), 2);
+
+        let second = split.pop().unwrap();
+        match second.embeddings {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![0.5, 0.6]),
+            _ => panic!("Expected Single variant"),
+        }
+
+        let first = split.pop().unwrap();
+        match first.embeddings {
+            BatchedEmbeddings::Batch(matrix) => {
+                assert_eq!(matrix.row(0), &[0.1, 0.2]);
+                assert_eq!(matrix.row(1), &[0.3, 0.4]);
+            }
+            _ => panic!("Expected Batch variant"),
+        }
     }
 }
@@ -11,6 +11,10 @@ pub mod python {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
             .add_submodule(&embeddings::python::bind(py, &format!("{name}.embedding"))?)?
+            .add_submodule(&embeddings::audio::python::bind(
+                py,
+                &format!("{name}.embedding_audio"),
+            )?)?
             .finish();
 
         Ok(module)
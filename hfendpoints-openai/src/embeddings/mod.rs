@@ -1,21 +1,36 @@
 use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use axum_extra::TypedHeader;
+use base64::Engine;
+use futures_util::stream::{self, Stream};
+use hfendpoints_core::environ::TryFromEnv;
 use hfendpoints_core::{EndpointContext, EndpointResult, Error};
+use hfendpoints_http::environ::Timeout;
 use hfendpoints_http::headers::RequestId;
-use hfendpoints_http::{Context, HttpError, HttpResult, RequestWithContext, EMBEDDINGS_TAG};
+use hfendpoints_http::{
+    Context, HttpError, HttpResult, Negotiated, RequestWithContext, ValidatedJson, EMBEDDINGS_TAG,
+};
 use hfendpoints_tasks::embedding::{
-    EmbeddingInput, EmbeddingParams, EmbeddingRequest, EmbeddingResponse,
+    BatchedEmbeddings, EmbeddingInput, EmbeddingParams, EmbeddingRequest, EmbeddingResponse,
 };
 use hfendpoints_tasks::{MaybeBatched, Usage};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::mem::size_of;
+use std::pin::Pin;
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::instrument;
+use tracing::{instrument, warn};
 use utoipa::ToSchema;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+mod models;
+mod native;
+mod tokenizer;
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(test, derive(Deserialize))]
 #[derive(Clone, Serialize, ToSchema)]
@@ -24,13 +39,41 @@ enum EmbeddingTag {
     Embedding,
 }
 
+/// An embedding vector as it's serialized back to the client: a raw `float` array, or -- when the
+/// request asked for [`EncodingFormat::Base64`] -- each `f32` packed as 4-byte little-endian IEEE
+/// 754 and the whole vector concatenated into one standard-base64 string, matching the OpenAI API
+/// so clients using the official SDK get the response-size win they rely on base64 for.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[cfg_attr(test, derive(Deserialize, PartialEq))]
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum EmbeddingVector {
+    Float(Vec<f32>),
+    Base64(String),
+}
+
+impl EmbeddingVector {
+    fn encode(values: Vec<f32>, format: EncodingFormat) -> Self {
+        match format {
+            EncodingFormat::Float => Self::Float(values),
+            EncodingFormat::Base64 => {
+                let mut bytes = Vec::with_capacity(values.len() * size_of::<f32>());
+                for value in values {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Self::Base64(base64::engine::general_purpose::STANDARD.encode(bytes))
+            }
+        }
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(test, derive(Deserialize))]
 #[derive(Clone, Serialize, ToSchema)]
 pub struct Embedding {
     object: EmbeddingTag,
     index: usize,
-    embedding: Vec<f32>,
+    embedding: EmbeddingVector,
 }
 
 impl Embedding {
@@ -38,7 +81,7 @@ impl Embedding {
         Self {
             object: EmbeddingTag::Embedding,
             index,
-            embedding,
+            embedding: EmbeddingVector::Float(embedding),
         }
     }
 }
@@ -84,6 +127,63 @@ impl OpenAiEmbeddingResponse {
             usage,
         }
     }
+
+    /// Applies OpenAI's Matryoshka `dimensions` behavior as a fallback for handlers that didn't
+    /// already truncate natively: truncates every embedding to its first `dimension` components
+    /// and re-normalizes the truncated vector to unit length (mirroring
+    /// `EmbeddingResponse::postprocess` since that method operates on `EmbeddingResponse`, not
+    /// the already-reshaped `OpenAiEmbeddingResponse`), so dot-product similarity stays valid
+    /// after truncation. A no-op when `dimension` is `None`.
+    fn truncate_and_normalize(mut self, dimension: Option<usize>) -> Result<Self, HttpError> {
+        let Some(dimension) = dimension else {
+            return Ok(self);
+        };
+
+        for embedding in &mut self.data {
+            let EmbeddingVector::Float(values) = &mut embedding.embedding else {
+                continue;
+            };
+
+            if dimension > values.len() {
+                return Err(HttpError::Validation(format!(
+                    "requested embedding dimension {dimension} exceeds the model's output dimension {}",
+                    values.len()
+                )));
+            }
+
+            values.truncate(dimension);
+
+            let norm = values.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                for x in values.iter_mut() {
+                    *x /= norm;
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Re-encodes every embedding vector into the format the client asked for. Conversion from
+    /// [`EmbeddingResponse`] always produces [`EncodingFormat::Float`] vectors since the
+    /// underlying handler has no notion of the OpenAI request's `encoding_format`; this is the
+    /// point where the originally requested format is applied before the response is sent.
+    fn encode_as(mut self, format: EncodingFormat) -> Self {
+        for embedding in &mut self.data {
+            if let EmbeddingVector::Float(values) = &embedding.embedding {
+                embedding.embedding = EmbeddingVector::encode(values.clone(), format);
+            }
+        }
+        self
+    }
+
+    /// Fills in the `model` field with the originally requested model name. Conversion from
+    /// [`EmbeddingResponse`] has no access to the request and so leaves it blank; `embed` patches
+    /// it in once the response comes back, the same way it applies `encoding_format`.
+    fn with_model(mut self, model: String) -> Self {
+        self.model = model;
+        self
+    }
 }
 
 impl IntoResponse for OpenAiEmbeddingResponse {
@@ -93,6 +193,59 @@ impl IntoResponse for OpenAiEmbeddingResponse {
     }
 }
 
+/// A single embedding emitted over `stream: true`, tagged with the request's `request_id` so
+/// clients can correlate chunks from concurrent requests sharing a connection.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Serialize, ToSchema)]
+pub struct EmbeddingChunk {
+    request_id: String,
+    #[serde(flatten)]
+    embedding: Embedding,
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>;
+
+/// Streams `response`'s embeddings one-by-one as `embedding.chunk` events tagged with
+/// `request_id`, terminated by OpenAI's `data: [DONE]` sentinel.
+fn embedding_event_stream(response: OpenAiEmbeddingResponse, request_id: String) -> EventStream {
+    let chunks = response
+        .data
+        .into_iter()
+        .map(move |embedding| EmbeddingChunk {
+            request_id: request_id.clone(),
+            embedding,
+        })
+        .collect::<Vec<_>>();
+
+    let chunks = stream::iter(chunks).filter_map(|chunk| async move {
+        match Event::default().json_data(chunk) {
+            Ok(event) => Some(Ok(event)),
+            Err(e) => {
+                warn!("Failed to serialize embedding stream event: {e}");
+                None
+            }
+        }
+    });
+
+    Box::pin(chunks.chain(stream::once(async { Ok(Event::default().data("[DONE]")) })))
+}
+
+/// [`Negotiated<OpenAiEmbeddingResponse>`] for a plain request, or a server-sent-events stream of
+/// per-embedding chunks for a `stream: true` request.
+pub enum EmbedResponse {
+    Buffered(Negotiated<OpenAiEmbeddingResponse>),
+    Stream(Sse<EventStream>),
+}
+
+impl IntoResponse for EmbedResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Buffered(response) => response.into_response(),
+            Self::Stream(sse) => sse.into_response(),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(test, derive(Serialize))]
@@ -104,6 +257,17 @@ pub struct OpenAiEmbeddingRequest {
     model: Option<String>,
     dimension: Option<usize>,
     user: Option<String>,
+
+    /// If set, the embeddings are streamed back one-by-one as server-sent events instead of
+    /// being buffered into a single JSON body.
+    #[serde(default)]
+    stream: bool,
+}
+
+impl hfendpoints_core::Validate for OpenAiEmbeddingRequest {
+    fn validate(&self) -> Result<(), hfendpoints_core::ValidationErrors> {
+        self.input.validate()
+    }
 }
 
 type OpenAiEmbeddingRequestWithContext = RequestWithContext<OpenAiEmbeddingRequest>;
@@ -123,15 +287,52 @@ pub async fn embed(
         EndpointContext<OpenAiEmbeddingRequestWithContext, OpenAiEmbeddingResponse>,
     >,
     request_id: TypedHeader<RequestId>,
-    Json(request): Json<OpenAiEmbeddingRequest>,
-) -> HttpResult<OpenAiEmbeddingResponse> {
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<OpenAiEmbeddingRequest>,
+) -> HttpResult<EmbedResponse> {
+    // Negotiate upfront so an unsupported `Accept` header is rejected before any inference work
+    // is scheduled.
+    let format = hfendpoints_http::ResponseFormat::negotiate(&headers)?;
+
+    // `encoding_format`, `dimension`, `model` and `stream` are requested here but the handler
+    // only deals in `EmbeddingResponse`, so it can't see any of them; capture them now and apply
+    // them once the raw float vectors come back.
+    let encoding_format = request.encoding_format;
+    let dimension = request.dimension;
+    let model = request.model.clone().unwrap_or_default();
+    let stream = request.stream;
+    let request_id_string = request_id.0.to_string();
+
+    // Reject a model outside the configured allow-list, and reject oversized input, before doing
+    // any inference work; count tokens up front in case the handler leaves `usage` unset.
+    models::validate_model(request.model.as_deref())?;
+    let fallback_usage =
+        tokenizer::validate_and_count_tokens(&request.input, request.model.as_deref())?;
+
     // Create request context
     let ctx = Context::new(request_id.0);
 
-    // Ask for the inference thread to handle it and wait for answers
-    let mut egress = state.schedule((request, ctx))?;
+    // Ask for the inference thread to handle it and wait for answers, riding out momentary
+    // handler saturation (a full IPC queue, a transient `Overloaded` rejection) instead of
+    // failing the call on the first hiccup.
+    let mut egress = state.schedule_with_retry((request, ctx)).await?;
     if let Some(response) = egress.recv().await {
-        Ok(response?)
+        let mut response = response?;
+        if response.usage.prompt_tokens == 0 && response.usage.total_tokens == 0 {
+            response.usage = fallback_usage;
+        }
+
+        let response = response.truncate_and_normalize(dimension)?;
+        let response = response.encode_as(encoding_format).with_model(model);
+
+        if stream {
+            return Ok(EmbedResponse::Stream(
+                Sse::new(embedding_event_stream(response, request_id_string))
+                    .keep_alive(KeepAlive::default()),
+            ));
+        }
+
+        Ok(EmbedResponse::Buffered(Negotiated(response, format)))
     } else {
         Err(HttpError::NoResponse)
     }
@@ -152,6 +353,11 @@ impl From<OpenAiEmbeddingRouter> for OpenApiRouter {
         OpenApiRouter::new()
             .routes(routes!(embed))
             .with_state(EndpointContext::new(value.0))
+            .layer(
+                Timeout::try_from_env()
+                    .unwrap_or_default()
+                    .layer_for("embeddings"),
+            )
     }
 }
 
@@ -162,7 +368,10 @@ impl TryFrom<OpenAiEmbeddingRequest> for EmbeddingRequest {
     fn try_from(value: OpenAiEmbeddingRequest) -> Result<Self, Self::Error> {
         Ok(Self::new(
             value.input,
-            EmbeddingParams::new(Some(true), None, None, None),
+            // `dimension` is forwarded so a handler with native Matryoshka support can honor it
+            // upstream; `embed` still applies `OpenAiEmbeddingResponse::truncate_and_normalize` as
+            // a fallback in case the handler ignores it.
+            EmbeddingParams::new(Some(true), None, None, None, value.dimension),
         ))
     }
 }
@@ -173,8 +382,9 @@ impl TryFrom<EmbeddingResponse> for OpenAiEmbeddingResponse {
     fn try_from(value: EmbeddingResponse) -> Result<Self, Self::Error> {
         let usage = value.usage.unwrap_or_default();
         let embeddings = match value.output {
-            MaybeBatched::Single(item) => vec![Embedding::new(0, item)],
-            MaybeBatched::Batch(items) => items
+            BatchedEmbeddings::Single(item) => vec![Embedding::new(0, item)],
+            BatchedEmbeddings::Batch(matrix) => matrix
+                .to_nested_vec()
                 .into_iter()
                 .enumerate()
                 .map(|(index, item)| Embedding::new(index, item))
@@ -212,6 +422,7 @@ pub mod python {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
             .add_class::<PyEmbeddingEndpoint>()?
+            .add_class::<crate::embeddings::native::python::NativeOnnxEmbeddingEndpoint>()?
             .finish();
 
         Ok(module)
@@ -232,7 +443,7 @@ mod tests {
         Router,
     };
     use hfendpoints_core::{EndpointContext, EndpointResult, Error};
-    use hfendpoints_tasks::embedding::{EmbeddingInput, EmbeddingResponse};
+    use hfendpoints_tasks::embedding::{BatchedEmbeddings, EmbeddingInput, EmbeddingMatrix, EmbeddingResponse};
     use hfendpoints_tasks::{MaybeBatched, Usage};
     use http_body_util::BodyExt;
     use hyper::body::Buf;
@@ -269,13 +480,14 @@ mod tests {
             dimension: None,
             encoding_format: EncodingFormat::Float,
             user: None,
+            stream: false,
         };
 
         // Create a test response
         let response = OpenAiEmbeddingResponse {
             object: EmbeddingResponseTag::List,
             data: vec![Embedding {
-                embedding: vec![0.1, 0.2, 0.3],
+                embedding: EmbeddingVector::Float(vec![0.1, 0.2, 0.3]),
                 index: 0,
                 object: EmbeddingTag::Embedding,
             }],
@@ -314,6 +526,67 @@ mod tests {
         assert_eq!(response_json.data.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_embedding_unsupported_accept_header() {
+        let (tx, _rx) = unbounded_channel();
+        let app = create_test_app(tx);
+
+        let request = OpenAiEmbeddingRequest {
+            input: MaybeBatched::Single(EmbeddingInput::Text(String::from("test text"))),
+            model: Some("test-model".into()),
+            dimension: None,
+            encoding_format: EncodingFormat::Float,
+            user: None,
+            stream: false,
+        };
+
+        let request = Request::builder()
+            .uri("/embeddings")
+            .method(http::Method::POST)
+            .header("content-type", "application/json")
+            .header("accept", "application/xml")
+            .header("x-request-id", "test-request-id")
+            .body(Body::from(serde_json::to_string(&request).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[tokio::test]
+    async fn test_embedding_rejects_model_outside_allow_list() {
+        unsafe {
+            std::env::set_var("HFENDPOINTS_SUPPORTED_EMBEDDING_MODELS", "allowed-model");
+        }
+
+        let (tx, _rx) = unbounded_channel();
+        let app = create_test_app(tx);
+
+        let request = OpenAiEmbeddingRequest {
+            input: MaybeBatched::Single(EmbeddingInput::Text(String::from("test text"))),
+            model: Some("unlisted-model".into()),
+            dimension: None,
+            encoding_format: EncodingFormat::Float,
+            user: None,
+            stream: false,
+        };
+
+        let request = Request::builder()
+            .uri("/embeddings")
+            .method(http::Method::POST)
+            .header("content-type", "application/json")
+            .header("x-request-id", "test-request-id")
+            .body(Body::from(serde_json::to_string(&request).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        unsafe {
+            std::env::remove_var("HFENDPOINTS_SUPPORTED_EMBEDDING_MODELS");
+        }
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_missing_request_id() {
         let (tx, _) = unbounded_channel();
@@ -394,6 +667,7 @@ mod tests {
             dimension: None,
             encoding_format: EncodingFormat::Float,
             user: None,
+            stream: false,
         };
 
         let request = Request::builder()
@@ -419,26 +693,26 @@ mod tests {
     fn test_embedding_response_to_openai_conversion_single() {
         // Test single embedding conversion
         let single_response = EmbeddingResponse {
-            output: MaybeBatched::Single(vec![0.1, 0.2, 0.3]),
+            output: BatchedEmbeddings::Single(vec![0.1, 0.2, 0.3]),
             usage: Some(Usage::new(1, 2)),
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(single_response).unwrap();
         assert_eq!(converted.data.len(), 1);
-        assert_eq!(converted.data[0].embedding, vec![0.1, 0.2, 0.3]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1, 0.2, 0.3]));
         assert_eq!(converted.data[0].index, 0);
         assert_eq!(converted.usage.prompt_tokens, 1);
         assert_eq!(converted.usage.total_tokens, 2);
 
         // Test usage conversion
         let response_without_usage = EmbeddingResponse {
-            output: MaybeBatched::Single(vec![0.1]),
+            output: BatchedEmbeddings::Single(vec![0.1]),
             usage: None,
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(response_without_usage).unwrap();
         assert_eq!(converted.data.len(), 1);
-        assert_eq!(converted.data[0].embedding, vec![0.1]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1]));
         assert_eq!(converted.data[0].index, 0);
         assert_eq!(converted.usage.prompt_tokens, 0);
         assert_eq!(converted.usage.total_tokens, 0);
@@ -448,13 +722,13 @@ mod tests {
     fn test_embedding_response_to_openai_conversion_single_no_usage() {
         // Test single embedding conversion
         let response_without_usage = EmbeddingResponse {
-            output: MaybeBatched::Single(vec![0.1]),
+            output: BatchedEmbeddings::Single(vec![0.1]),
             usage: None,
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(response_without_usage).unwrap();
         assert_eq!(converted.data.len(), 1);
-        assert_eq!(converted.data[0].embedding, vec![0.1]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1]));
         assert_eq!(converted.data[0].index, 0);
         assert_eq!(converted.usage.prompt_tokens, 0);
         assert_eq!(converted.usage.total_tokens, 0);
@@ -464,30 +738,30 @@ mod tests {
     fn test_embedding_response_to_openai_conversion_batched() {
         // Test batched embeddings conversion
         let batched_response = EmbeddingResponse {
-            output: MaybeBatched::Batch(vec![vec![0.1, 0.2], vec![0.3, 0.4]]),
+            output: BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(vec![0.1, 0.2, 0.3, 0.4], 2, 2)),
             usage: Some(Usage::new(2, 3)),
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(batched_response).unwrap();
         assert_eq!(converted.data.len(), 2);
-        assert_eq!(converted.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1, 0.2]));
         assert_eq!(converted.data[0].index, 0);
-        assert_eq!(converted.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(converted.data[1].embedding, EmbeddingVector::Float(vec![0.3, 0.4]));
         assert_eq!(converted.data[1].index, 1);
         assert_eq!(converted.usage.prompt_tokens, 2);
         assert_eq!(converted.usage.total_tokens, 3);
 
         // Test usage conversion
         let response_without_usage = EmbeddingResponse {
-            output: MaybeBatched::Batch(vec![vec![0.1, 0.2], vec![0.3, 0.4]]),
+            output: BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(vec![0.1, 0.2, 0.3, 0.4], 2, 2)),
             usage: None,
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(response_without_usage).unwrap();
         assert_eq!(converted.data.len(), 2);
-        assert_eq!(converted.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1, 0.2]));
         assert_eq!(converted.data[0].index, 0);
-        assert_eq!(converted.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(converted.data[1].embedding, EmbeddingVector::Float(vec![0.3, 0.4]));
         assert_eq!(converted.data[1].index, 1);
         assert_eq!(converted.usage.prompt_tokens, 0);
         assert_eq!(converted.usage.total_tokens, 0);
@@ -497,17 +771,95 @@ mod tests {
     fn test_embedding_response_to_openai_conversion_batched_no_usage() {
         // Test batched embeddings conversion
         let response_without_usage = EmbeddingResponse {
-            output: MaybeBatched::Batch(vec![vec![0.1, 0.2], vec![0.3, 0.4]]),
+            output: BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(vec![0.1, 0.2, 0.3, 0.4], 2, 2)),
             usage: None,
         };
 
         let converted = OpenAiEmbeddingResponse::try_from(response_without_usage).unwrap();
         assert_eq!(converted.data.len(), 2);
-        assert_eq!(converted.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(converted.data[0].embedding, EmbeddingVector::Float(vec![0.1, 0.2]));
         assert_eq!(converted.data[0].index, 0);
-        assert_eq!(converted.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(converted.data[1].embedding, EmbeddingVector::Float(vec![0.3, 0.4]));
         assert_eq!(converted.data[1].index, 1);
         assert_eq!(converted.usage.prompt_tokens, 0);
         assert_eq!(converted.usage.total_tokens, 0);
     }
+
+    #[test]
+    fn test_truncate_and_normalize_is_a_no_op_without_dimension() {
+        let response = OpenAiEmbeddingResponse::new(
+            vec![Embedding::new(0, vec![0.1, 0.2, 0.3])],
+            "test-model".into(),
+            Usage::new(1, 1),
+        );
+
+        let response = response.truncate_and_normalize(None).unwrap();
+        assert_eq!(
+            response.data[0].embedding,
+            EmbeddingVector::Float(vec![0.1, 0.2, 0.3])
+        );
+    }
+
+    #[test]
+    fn test_truncate_and_normalize_truncates_then_renormalizes() {
+        let response = OpenAiEmbeddingResponse::new(
+            vec![Embedding::new(0, vec![3.0, 4.0, 0.0, 0.0])],
+            "test-model".into(),
+            Usage::new(1, 1),
+        );
+
+        let response = response.truncate_and_normalize(Some(2)).unwrap();
+        assert_eq!(
+            response.data[0].embedding,
+            EmbeddingVector::Float(vec![0.6, 0.8])
+        );
+    }
+
+    #[test]
+    fn test_truncate_and_normalize_rejects_dimension_too_large() {
+        let response = OpenAiEmbeddingResponse::new(
+            vec![Embedding::new(0, vec![1.0, 2.0])],
+            "test-model".into(),
+            Usage::new(1, 1),
+        );
+
+        assert!(response.truncate_and_normalize(Some(8)).is_err());
+    }
+
+    #[test]
+    fn test_encode_as_float_is_a_no_op() {
+        let response = OpenAiEmbeddingResponse::new(
+            vec![Embedding::new(0, vec![0.1, 0.2, 0.3])],
+            "test-model".into(),
+            Usage::new(1, 1),
+        );
+
+        let encoded = response.encode_as(EncodingFormat::Float);
+        assert_eq!(
+            encoded.data[0].embedding,
+            EmbeddingVector::Float(vec![0.1, 0.2, 0.3])
+        );
+    }
+
+    #[test]
+    fn test_encode_as_base64_packs_little_endian_f32() {
+        let response = OpenAiEmbeddingResponse::new(
+            vec![Embedding::new(0, vec![0.1, 0.2])],
+            "test-model".into(),
+            Usage::new(1, 1),
+        );
+
+        let encoded = response.encode_as(EncodingFormat::Base64);
+        let EmbeddingVector::Base64(b64) = &encoded.data[0].embedding else {
+            panic!("expected a base64-encoded embedding");
+        };
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .unwrap();
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&0.1f32.to_le_bytes());
+        expected.extend_from_slice(&0.2f32.to_le_bytes());
+        assert_eq!(bytes, expected);
+    }
 }
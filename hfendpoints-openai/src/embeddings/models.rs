@@ -0,0 +1,73 @@
+use hfendpoints_http::HttpError;
+
+/// Comma-separated list of model identifiers this endpoint accepts, e.g.
+/// `text-embedding-3-small,text-embedding-3-large`. Unset (the default) accepts any model name,
+/// matching a single-model deployment that doesn't care what the client calls it.
+const ENV_VAR_NAME: &str = "HFENDPOINTS_SUPPORTED_EMBEDDING_MODELS";
+
+fn supported_models() -> Option<Vec<String>> {
+    let value = std::env::var(ENV_VAR_NAME).ok()?;
+    Some(
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|model| !model.is_empty())
+            .map(String::from)
+            .collect(),
+    )
+}
+
+/// Rejects `model` with a `400` listing the supported names when an allow-list is configured and
+/// doesn't include it. An unset allow-list accepts anything, including no model name at all.
+pub fn validate_model(model: Option<&str>) -> Result<(), HttpError> {
+    let Some(allowed) = supported_models() else {
+        return Ok(());
+    };
+
+    match model {
+        Some(model) if allowed.iter().any(|supported| supported == model) => Ok(()),
+        _ => Err(HttpError::Validation(format!(
+            "model '{}' is not supported; supported models are: {}",
+            model.unwrap_or("<unset>"),
+            allowed.join(", ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_validate_model_accepts_anything_when_unset() {
+        unsafe {
+            env::remove_var(ENV_VAR_NAME);
+        }
+        assert!(validate_model(Some("whatever-model")).is_ok());
+        assert!(validate_model(None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_model_accepts_listed_model() {
+        unsafe {
+            env::set_var(ENV_VAR_NAME, "model-a, model-b");
+        }
+        assert!(validate_model(Some("model-b")).is_ok());
+        unsafe {
+            env::remove_var(ENV_VAR_NAME);
+        }
+    }
+
+    #[test]
+    fn test_validate_model_rejects_unlisted_model() {
+        unsafe {
+            env::set_var(ENV_VAR_NAME, "model-a");
+        }
+        assert!(validate_model(Some("model-c")).is_err());
+        assert!(validate_model(None).is_err());
+        unsafe {
+            env::remove_var(ENV_VAR_NAME);
+        }
+    }
+}
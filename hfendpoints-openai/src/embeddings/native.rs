@@ -0,0 +1,146 @@
+//! A Rust-native embedding handler, so an ONNX-exported encoder can back `/embeddings` with no
+//! Python in the hot path instead of only through [`super::python::PyHandler`]'s Python callback.
+
+use crate::embeddings::{OpenAiEmbeddingRequest, OpenAiEmbeddingResponse};
+use hfendpoints_core::environ::TryFromEnv;
+use hfendpoints_core::Error;
+use hfendpoints_http::Context;
+use hfendpoints_inference_engine::onnx::{OnnxEmbeddingModel, OnnxSessionOptions};
+use hfendpoints_inference_engine::tokenizer::{EmbeddingTokenizer, TokenizerOptions};
+use hfendpoints_tasks::embedding::EmbeddingRequest;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Tokenizes text inputs with [`EmbeddingTokenizer`], then hands the tokenized request to an
+/// [`OnnxEmbeddingModel`] -- the same composition a Python handler would otherwise have to
+/// reimplement on the other side of the FFI boundary.
+pub struct NativeOnnxEmbeddingHandler {
+    tokenizer: EmbeddingTokenizer,
+    model: OnnxEmbeddingModel,
+}
+
+impl NativeOnnxEmbeddingHandler {
+    /// Loads `tokenizer.json` and the ONNX encoder graph at `onnx_model_path`, both tuned from
+    /// the environment the same way the rest of `hfendpoints-inference-engine` is.
+    pub fn from_files(onnx_model_path: impl AsRef<Path>) -> Result<Self, Error> {
+        let tokenizer_options =
+            TokenizerOptions::try_from_env().map_err(Error::Environment)?;
+        let tokenizer = EmbeddingTokenizer::from_options(tokenizer_options, HashMap::new())
+            .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+        let session_options = OnnxSessionOptions::try_from_env().map_err(Error::Environment)?;
+        let model = OnnxEmbeddingModel::from_file(onnx_model_path, session_options)
+            .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+        Ok(Self { tokenizer, model })
+    }
+}
+
+impl hfendpoints_core::Handler for NativeOnnxEmbeddingHandler {
+    type Request = (OpenAiEmbeddingRequest, Context);
+    type Response = OpenAiEmbeddingResponse;
+
+    async fn on_request(
+        &self,
+        (request, _context): Self::Request,
+    ) -> Result<Self::Response, Error> {
+        let request: EmbeddingRequest = request.try_into()?;
+        let params = request.parameters().clone();
+
+        let (inputs, usage) = self
+            .tokenizer
+            .prepare(request.inputs, &params)
+            .map_err(|e| Error::Runtime(e.to_string().into()))?;
+
+        let mut response = self
+            .model
+            .on_request(EmbeddingRequest::new(inputs, params))
+            .await?;
+        // The tokenizer's own token count reflects what was actually tokenized (post prompt
+        // templating), which is more accurate than whatever `OnnxEmbeddingModel` reports.
+        response.usage = Some(usage);
+
+        response.try_into()
+    }
+}
+
+#[cfg(feature = "python")]
+pub mod python {
+    use super::NativeOnnxEmbeddingHandler;
+    use crate::embeddings::OpenAiEmbeddingRouter;
+    use hfendpoints_core::environ::{Concurrency, TryFromEnv};
+    use hfendpoints_core::{wait_for_requests, Endpoint, Error, HealthReporter};
+    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use hfendpoints_http::{serve_http, ApiDoc, Driver, ServeAddress};
+    use pyo3::exceptions::PyRuntimeError;
+    use pyo3::prelude::*;
+    use std::sync::Arc;
+    use tokio::sync::mpsc::unbounded_channel;
+    use tracing::{error, info, instrument};
+    use utoipa::OpenApi;
+    use utoipa_axum::{router::OpenApiRouter, routes};
+
+    /// Python-constructible `/embeddings` endpoint backed by a native ONNX Runtime encoder
+    /// instead of a Python callback -- takes a path to an `optimum-cli export onnx`-produced
+    /// encoder graph rather than a Python handler object.
+    #[pyclass(name = "NativeOnnxEmbeddingEndpoint")]
+    pub struct NativeOnnxEmbeddingEndpoint(Arc<NativeOnnxEmbeddingHandler>, Driver);
+
+    impl Endpoint<ServeAddress> for NativeOnnxEmbeddingEndpoint {
+        #[instrument(skip_all)]
+        async fn serve(&self, address: ServeAddress) -> Result<(), Error> {
+            let (sender, receiver) = unbounded_channel();
+            let router = OpenAiEmbeddingRouter(sender);
+
+            let handler = Arc::clone(&self.0);
+            let concurrency = Concurrency::try_from_env().map_err(Error::Environment)?;
+            let (health_reporter, health) = HealthReporter::new();
+            let _ = pyo3_async_runtimes::tokio::get_runtime().spawn(wait_for_requests(
+                receiver,
+                handler,
+                Vec::new(),
+                concurrency,
+                Arc::new(health_reporter),
+            ));
+
+            info!("Starting native ONNX embedding endpoint at {:?}", &address);
+
+            match pyo3_async_runtimes::tokio::get_runtime()
+                .spawn(serve_http(address, router, self.1.clone(), Some(health)))
+                .await
+            {
+                Ok(res) => Ok(res?),
+                Err(join_error) => Err(Error::Runtime(join_error.to_string().into())),
+            }
+        }
+    }
+
+    #[pymethods]
+    impl NativeOnnxEmbeddingEndpoint {
+        #[instrument]
+        #[new]
+        fn new(onnx_model_path: String) -> PyResult<Self> {
+            let handler = NativeOnnxEmbeddingHandler::from_files(onnx_model_path)
+                .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
+            Ok(Self(Arc::new(handler), Driver::new()))
+        }
+
+        #[instrument(skip_all)]
+        async fn _serve_(&self, interface: String, port: u16) -> PyResult<()> {
+            let address = ServeAddress::parse(&interface, port);
+            if let Err(err) = self.serve(address).await {
+                error!("Caught error while serving native ONNX embedding endpoint: {err}");
+                Err(PyRuntimeError::new_err(err.to_string()))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Begin a graceful shutdown: stop accepting new connections and return from
+        /// `_serve_`/`run` once in-flight requests have drained.
+        #[instrument(skip_all)]
+        fn stop(&self) {
+            self.1.stop();
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::embeddings::EmbeddingInput;
+use hfendpoints_http::HttpError;
+use hfendpoints_tasks::{MaybeBatched, Usage};
+use std::sync::OnceLock;
+use tiktoken_rs::CoreBPE;
+
+/// The context window (in tokens) OpenAI enforces for its embedding models. Kept as a table
+/// keyed by model name -- like the `max_token()`/`dimensions()` maps other OpenAI-compatible
+/// embedders carry -- even though every model OpenAI ships today shares the same limit, so a
+/// future model with a different window only needs a new match arm.
+fn context_window(model: Option<&str>) -> usize {
+    match model {
+        Some("text-embedding-3-small") => 8191,
+        Some("text-embedding-3-large") => 8191,
+        Some("text-embedding-ada-002") => 8191,
+        _ => 8191,
+    }
+}
+
+fn encoder() -> &'static CoreBPE {
+    static ENCODER: OnceLock<CoreBPE> = OnceLock::new();
+    ENCODER.get_or_init(|| {
+        tiktoken_rs::cl100k_base().expect("cl100k_base's bundled ranks should always load")
+    })
+}
+
+fn count_tokens(input: &EmbeddingInput) -> usize {
+    match input {
+        EmbeddingInput::Text(text) => encoder().encode_with_special_tokens(text).len(),
+        EmbeddingInput::Tokens(tokens) => tokens.len(),
+    }
+}
+
+/// Counts `input`'s tokens element-by-element, rejecting with a `400` the moment one element
+/// exceeds `model`'s context window instead of letting an oversized request reach the backend
+/// and fail there. Returns the aggregate token count as a [`Usage`] (embeddings have no separate
+/// completion tokens, so `prompt_tokens` and `total_tokens` are the same number) for callers that
+/// leave `usage` unset to fall back on.
+pub fn validate_and_count_tokens(
+    input: &MaybeBatched<EmbeddingInput>,
+    model: Option<&str>,
+) -> Result<Usage, HttpError> {
+    let limit = context_window(model);
+    let mut total = 0usize;
+
+    let mut check = |item: &EmbeddingInput| -> Result<(), HttpError> {
+        let tokens = count_tokens(item);
+        if tokens > limit {
+            return Err(HttpError::Validation(format!(
+                "input has {tokens} tokens, which exceeds the {limit}-token context window for model '{}'",
+                model.unwrap_or("default")
+            )));
+        }
+        total += tokens;
+        Ok(())
+    };
+
+    match input {
+        MaybeBatched::Single(item) => check(item)?,
+        MaybeBatched::Batched(items) => {
+            for item in items {
+                check(item)?;
+            }
+        }
+    }
+
+    Ok(Usage::same(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_and_count_tokens_single() {
+        let input = MaybeBatched::Single(EmbeddingInput::Text("hello world".into()));
+        let usage = validate_and_count_tokens(&input, None).unwrap();
+        assert_eq!(usage.prompt_tokens, usage.total_tokens);
+        assert!(usage.total_tokens > 0);
+    }
+
+    #[test]
+    fn test_validate_and_count_tokens_sums_batch() {
+        let input = MaybeBatched::Batched(vec![
+            EmbeddingInput::Text("hello".into()),
+            EmbeddingInput::Text("world".into()),
+        ]);
+        let single = validate_and_count_tokens(
+            &MaybeBatched::Single(EmbeddingInput::Text("hello".into())),
+            None,
+        )
+        .unwrap();
+        let batch = validate_and_count_tokens(&input, None).unwrap();
+        assert_eq!(batch.total_tokens, single.total_tokens * 2);
+    }
+
+    #[test]
+    fn test_validate_and_count_tokens_pretokenized_input_counts_tokens_directly() {
+        let input = MaybeBatched::Single(EmbeddingInput::Tokens(vec![1, 2, 3, 4]));
+        let usage = validate_and_count_tokens(&input, None).unwrap();
+        assert_eq!(usage.total_tokens, 4);
+    }
+
+    #[test]
+    fn test_validate_and_count_tokens_rejects_oversized_input() {
+        let huge_text = "word ".repeat(10_000);
+        let input = MaybeBatched::Single(EmbeddingInput::Text(huge_text));
+        assert!(validate_and_count_tokens(&input, None).is_err());
+    }
+}
@@ -200,6 +200,10 @@ pub mod python {
     pub fn bind<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyModule>> {
         let module = ImportablePyModuleBuilder::new(py, name)?
             .defaults()?
+            .add_submodule(&crate::embeddings::python::bind(
+                py,
+                &format!("{name}.embedding"),
+            )?)?
             .finish();
 
         module.add_function(wrap_pyfunction!(run, &module)?)?;
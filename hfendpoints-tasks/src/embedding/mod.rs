@@ -1,6 +1,7 @@
 use crate::{EndpointRequest, EndpointResponse, MaybeBatched, Usage};
-use hfendpoints_core::Handler;
-use serde::{Deserialize, Serialize};
+use hfendpoints_core::{Error, Handler, Validate, ValidationErrors};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Serialize, Serializer};
 use utoipa::ToSchema;
 
 #[cfg(feature = "python")]
@@ -23,11 +24,27 @@ pub enum TruncationDirection {
 pub enum EmbeddingInput {
     Text(String),
     Tokens(Vec<u32>),
+
+    /// Raw audio bytes to embed into a fixed-length speaker/voiceprint vector (e.g. an
+    /// ECAPA-TDNN-style embedding), for speaker-verification or audio-search use cases. Always
+    /// populated by the multipart audio-embedding route, never expected directly in a JSON
+    /// request body.
+    Audio(Vec<u8>),
+}
+
+impl Validate for EmbeddingInput {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Self::Text(text) => text.validate(),
+            Self::Tokens(tokens) => tokens.validate(),
+            Self::Audio(bytes) => bytes.validate(),
+        }
+    }
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(feature = "python", derive(IntoPyObject))]
-#[derive(Clone, Default, Deserialize, Serialize, ToSchema)]
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct EmbeddingParams {
     /// Flag indicating whether the embedding vector should be normalized to length 1
     pub normalize: Option<bool>,
@@ -69,11 +86,218 @@ impl EmbeddingParams {
     }
 }
 
+impl Validate for EmbeddingParams {
+    /// None of `EmbeddingParams`' fields are constrained today; this exists so
+    /// `EmbeddingRequest` can derive its own `Validate` by delegating uniformly to both of its
+    /// fields.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
 /// Represents a request to compute embeddings
 pub type EmbeddingRequest = EndpointRequest<MaybeBatched<EmbeddingInput>, EmbeddingParams>;
 
+/// A batch of embeddings backed by a single row-major, flat buffer rather than nested
+/// `Vec<Vec<f32>>`.
+///
+/// This lets a batch produced by a zero-copy NumPy ingestion (see `PyEmbeddingResponse::from_numpy`)
+/// keep its original C-contiguous layout end-to-end: `Serialize` walks row slices over the flat
+/// buffer instead of materializing one allocation per row.
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, PartialEq, ToSchema)]
+#[schema(value_type = Vec<Vec<f32>>)]
+pub struct EmbeddingMatrix {
+    /// Row-major flattened embeddings, `rows * cols` entries long
+    data: Vec<f32>,
+
+    /// Number of embeddings in this batch
+    rows: usize,
+
+    /// Dimensionality of each embedding
+    cols: usize,
+}
+
+impl EmbeddingMatrix {
+    pub fn from_flat(data: Vec<f32>, rows: usize, cols: usize) -> Self {
+        Self { data, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Borrow the `index`-th row without copying
+    pub fn row(&self, index: usize) -> &[f32] {
+        &self.data[index * self.cols..(index + 1) * self.cols]
+    }
+
+    /// Iterate over each row as a mutable slice, without reallocating the underlying buffer
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [f32]> {
+        self.data.chunks_exact_mut(self.cols)
+    }
+
+    /// Truncate every row down to its first `new_cols` columns, compacting the flat buffer
+    /// in place rather than reallocating a new one per row.
+    pub fn truncate_cols(mut self, new_cols: usize) -> Self {
+        for row in 0..self.rows {
+            self.data
+                .copy_within(row * self.cols..row * self.cols + new_cols, row * new_cols);
+        }
+        self.data.truncate(self.rows * new_cols);
+        self.cols = new_cols;
+        self
+    }
+
+    /// Materialize the flat buffer back into a nested `Vec<Vec<f32>>`, for Python callers (and
+    /// other consumers) that expect a list-of-lists rather than a flat view.
+    pub fn to_nested_vec(&self) -> Vec<Vec<f32>> {
+        self.data.chunks_exact(self.cols).map(<[f32]>::to_vec).collect()
+    }
+
+    /// Borrow every row of the batch as one contiguous slice, without copying.
+    pub fn as_flat_slice(&self) -> &[f32] {
+        &self.data
+    }
+}
+
+impl Serialize for EmbeddingMatrix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.rows))?;
+        for row in self.data.chunks_exact(self.cols) {
+            seq.serialize_element(row)?;
+        }
+        seq.end()
+    }
+}
+
+/// Represents either a single embedding vector, or a batch of them
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, PartialEq, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum BatchedEmbeddings {
+    Single(Vec<f32>),
+    Batch(EmbeddingMatrix),
+}
+
+impl BatchedEmbeddings {
+    /// Borrow every embedding value as one contiguous slice, without copying -- the `Single` and
+    /// `Batch` variants are both already backed by a single flat `Vec<f32>`.
+    pub fn as_flat_slice(&self) -> &[f32] {
+        match self {
+            Self::Single(values) => values,
+            Self::Batch(matrix) => matrix.as_flat_slice(),
+        }
+    }
+}
+
 /// Represent a response to
-pub type EmbeddingResponse = EndpointResponse<MaybeBatched<Vec<f32>>, Usage>;
+pub type EmbeddingResponse = EndpointResponse<BatchedEmbeddings, Usage>;
+
+impl EmbeddingResponse {
+    /// Apply the Matryoshka truncation and L2 normalization requested through `params` to every
+    /// embedding carried by this response.
+    ///
+    /// Truncation is applied before normalization, so a truncated vector is renormalized to unit
+    /// length rather than inheriting the norm of the full untruncated vector.
+    pub fn postprocess(mut self, params: &EmbeddingParams) -> Result<Self, Error> {
+        if let Some(dimension) = params.dimension {
+            if dimension == 0 {
+                return Err(Error::Runtime(
+                    "requested embedding dimension must be greater than zero".into(),
+                ));
+            }
+
+            self.output = match self.output {
+                BatchedEmbeddings::Single(mut embedding) => {
+                    Self::check_dimension(dimension, embedding.len())?;
+                    embedding.truncate(dimension);
+                    BatchedEmbeddings::Single(embedding)
+                }
+                BatchedEmbeddings::Batch(matrix) => {
+                    Self::check_dimension(dimension, matrix.cols())?;
+                    BatchedEmbeddings::Batch(matrix.truncate_cols(dimension))
+                }
+            };
+        }
+
+        if params.normalize == Some(true) {
+            match &mut self.output {
+                BatchedEmbeddings::Single(embedding) => Self::normalize(embedding),
+                BatchedEmbeddings::Batch(matrix) => {
+                    for row in matrix.rows_mut() {
+                        Self::normalize(row);
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn check_dimension(dimension: usize, actual: usize) -> Result<(), Error> {
+        if dimension > actual {
+            return Err(Error::Runtime(
+                format!(
+                    "requested embedding dimension {dimension} exceeds the model's output dimension {actual}"
+                )
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn normalize(embedding: &mut [f32]) {
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for x in embedding.iter_mut() {
+                *x /= norm;
+            }
+        }
+    }
+
+    /// Cosine similarity between this response's embedding and `other`'s -- the dot product of
+    /// both vectors after L2-normalizing each, in `[-1.0, 1.0]`. Only defined for two single
+    /// (non-batched) embeddings of the same dimension, e.g. scoring one speaker enrollment clip
+    /// against one test clip.
+    pub fn cosine_similarity(&self, other: &Self) -> Result<f32, Error> {
+        let (BatchedEmbeddings::Single(a), BatchedEmbeddings::Single(b)) =
+            (&self.output, &other.output)
+        else {
+            return Err(Error::Runtime(
+                "cosine_similarity requires two single (non-batched) embeddings".into(),
+            ));
+        };
+
+        if a.len() != b.len() {
+            return Err(Error::Runtime(
+                format!(
+                    "cannot compare embeddings of different dimension ({} vs {})",
+                    a.len(),
+                    b.len()
+                )
+                .into(),
+            ));
+        }
+
+        let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return Ok(0.0);
+        }
+
+        let dot = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<f32>();
+        Ok(dot / (norm_a * norm_b))
+    }
+}
 
 /// Helper trait to implement `Handler` specification for Transcription endpoints
 pub trait EmbeddingHandler:
@@ -85,13 +309,24 @@ pub trait EmbeddingHandler:
 
 #[cfg(feature = "python")]
 pub mod python {
-    use crate::embedding::{EmbeddingRequest, EmbeddingResponse};
+    use crate::embedding::{BatchedEmbeddings, EmbeddingMatrix, EmbeddingRequest, EmbeddingResponse};
     use crate::{EndpointResponse, MaybeBatched, Usage};
-    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use hfendpoints_binding_python::{fill_view_from_readonly_data, ImportablePyModuleBuilder};
     use numpy::{PyArray1, PyArray2, PyArrayMethods, PyUntypedArrayMethods};
+    use pyo3::exceptions::PyValueError;
+    use pyo3::ffi::Py_buffer;
     use pyo3::prelude::*;
     use pyo3::types::{PyDict, PyList};
     use pyo3::IntoPyObjectExt;
+    use std::ffi::CString;
+    use std::mem::size_of_val;
+    use tracing::{debug, instrument};
+
+    /// Reinterprets `values` as its raw little-endian byte representation, without copying --
+    /// valid on every platform `hfendpoints` targets (x86_64 and aarch64 are both little-endian).
+    fn as_le_bytes(values: &[f32]) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u8, size_of_val(values)) }
+    }
 
     #[pyclass(name = "EmbeddingRequest", frozen)]
     pub struct PyEmbeddingRequest(pub EmbeddingRequest);
@@ -147,8 +382,32 @@ pub mod python {
             prompt_tokens: usize,
             num_tokens: usize,
         ) -> PyResult<Self> {
+            let is_batch = embeddings
+                .get_item(0)
+                .is_ok_and(|item| item.downcast::<PyList>().is_ok());
+
+            let output = if is_batch {
+                let nested: Vec<Vec<f32>> = embeddings.extract()?;
+                let cols = nested.first().map_or(0, Vec::len);
+                let rows = nested.len();
+
+                let mut data = Vec::with_capacity(rows * cols);
+                for row in nested {
+                    if row.len() != cols {
+                        return Err(PyValueError::new_err(
+                            "all embeddings in a batch must share the same dimension",
+                        ));
+                    }
+                    data.extend(row);
+                }
+
+                BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(data, rows, cols))
+            } else {
+                BatchedEmbeddings::Single(embeddings.extract()?)
+            };
+
             Ok(Self(EndpointResponse {
-                output: embeddings.extract()?,
+                output,
                 usage: Some(Usage::new(prompt_tokens, num_tokens)),
             }))
         }
@@ -172,20 +431,19 @@ pub mod python {
         ) -> PyResult<Self> {
             let output = match embeddings {
                 SupportedEmbeddingsArray::Single(item) => unsafe {
-                    MaybeBatched::Single(Vec::from_raw_parts(item.data(), item.len(), item.len()))
+                    BatchedEmbeddings::Single(Vec::from_raw_parts(item.data(), item.len(), item.len()))
                 },
 
-                //TODO(mfuntowicz) This does a copy for now
-                SupportedEmbeddingsArray::Batched(items) => {
-                    let hidden = items.dims()[1];
-                    let buffer = items.to_vec()?;
-                    MaybeBatched::Batch(
-                        buffer
-                            .chunks_exact(hidden)
-                            .map(|slice| slice.to_vec())
-                            .collect(),
-                    )
-                }
+                SupportedEmbeddingsArray::Batched(items) => unsafe {
+                    let rows = items.dims()[0];
+                    let cols = items.dims()[1];
+                    let len = rows * cols;
+                    BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(
+                        Vec::from_raw_parts(items.data(), len, len),
+                        rows,
+                        cols,
+                    ))
+                },
             };
 
             Ok(Self(EndpointResponse {
@@ -196,16 +454,35 @@ pub mod python {
 
         fn __repr__(&self) -> String {
             match &self.0.output {
-                MaybeBatched::Single(single) => {
+                BatchedEmbeddings::Single(single) => {
                     format!("EmbeddingResponse(<{}xf32>)", single.len())
                 }
-                MaybeBatched::Batch(batched) => format!(
-                    "EmbeddingResponse(<{}x{}xf32>)",
-                    batched.len(),
-                    batched.first().map_or(0, |item| item.len())
-                ),
+                BatchedEmbeddings::Batch(matrix) => {
+                    format!("EmbeddingResponse(<{}x{}xf32>)", matrix.rows(), matrix.cols())
+                }
             }
         }
+
+        /// Expose the embedding vector(s) as a borrowed, read-only buffer of little-endian `f32`
+        /// bytes -- lets a Python consumer wrap this in `memoryview`/`numpy.frombuffer` without
+        /// allocating an intermediate list, which matters once vectors are thousands of dimensions wide.
+        #[instrument(skip(slf, buffer))]
+        unsafe fn __getbuffer__(
+            slf: Bound<'_, Self>,
+            buffer: *mut Py_buffer,
+            flags: i32,
+        ) -> PyResult<()> {
+            debug!("Acquiring a memoryview over embedding data (flags={})", flags);
+            let data = as_le_bytes(slf.borrow().0.output.as_flat_slice());
+            unsafe { fill_view_from_readonly_data(buffer, flags, data, slf.into_any()) }
+        }
+
+        #[instrument(skip_all)]
+        unsafe fn __releasebuffer__(&self, buffer: *mut Py_buffer) {
+            debug!("Releasing Python memoryview");
+            // Release memory held by the format string
+            drop(unsafe { CString::from_raw((*buffer).format) });
+        }
     }
 
     /// Bind this module to the python's wheel  
@@ -271,6 +548,158 @@ mod tests {
         assert_eq!(request.inputs, inputs)
     }
 
+    #[test]
+    fn test_postprocess_truncates_before_normalizing() {
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![3.0, 4.0, 0.0, 0.0]),
+            usage: None,
+        };
+        let params = EmbeddingParams {
+            dimension: Some(2),
+            normalize: Some(true),
+            ..Default::default()
+        };
+
+        let response = response.postprocess(&params).expect("postprocess failed");
+        match response.output {
+            BatchedEmbeddings::Single(embedding) => {
+                assert_eq!(embedding, vec![0.6, 0.8]);
+            }
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_leaves_zero_vector_untouched() {
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![0.0, 0.0]),
+            usage: None,
+        };
+        let params = EmbeddingParams {
+            normalize: Some(true),
+            ..Default::default()
+        };
+
+        let response = response.postprocess(&params).expect("postprocess failed");
+        match response.output {
+            BatchedEmbeddings::Single(embedding) => assert_eq!(embedding, vec![0.0, 0.0]),
+            _ => panic!("Expected Single variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_truncates_batch() {
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(
+                vec![3.0, 4.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+                2,
+                4,
+            )),
+            usage: None,
+        };
+        let params = EmbeddingParams {
+            dimension: Some(2),
+            normalize: Some(true),
+            ..Default::default()
+        };
+
+        let response = response.postprocess(&params).expect("postprocess failed");
+        match response.output {
+            BatchedEmbeddings::Batch(matrix) => {
+                assert_eq!(matrix.cols(), 2);
+                assert_eq!(matrix.row(0), &[0.6, 0.8]);
+                assert_eq!(matrix.row(1), &[1.0, 0.0]);
+            }
+            _ => panic!("Expected Batch variant"),
+        }
+    }
+
+    #[test]
+    fn test_postprocess_rejects_dimension_too_large() {
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 2.0]),
+            usage: None,
+        };
+        let params = EmbeddingParams {
+            dimension: Some(8),
+            ..Default::default()
+        };
+
+        assert!(response.postprocess(&params).is_err());
+    }
+
+    #[test]
+    fn test_postprocess_rejects_zero_dimension() {
+        let response = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 2.0]),
+            usage: None,
+        };
+        let params = EmbeddingParams {
+            dimension: Some(0),
+            ..Default::default()
+        };
+
+        assert!(response.postprocess(&params).is_err());
+    }
+
+    #[test]
+    fn test_cosine_similarity_of_identical_vectors_is_one() {
+        let a = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![3.0, 4.0]),
+            usage: None,
+        };
+        let b = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![3.0, 4.0]),
+            usage: None,
+        };
+
+        let similarity = a.cosine_similarity(&b).expect("cosine_similarity failed");
+        assert!((similarity - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_cosine_similarity_of_orthogonal_vectors_is_zero() {
+        let a = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 0.0]),
+            usage: None,
+        };
+        let b = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![0.0, 1.0]),
+            usage: None,
+        };
+
+        let similarity = a.cosine_similarity(&b).expect("cosine_similarity failed");
+        assert!(similarity.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_cosine_similarity_rejects_batched_embeddings() {
+        let a = EmbeddingResponse {
+            output: BatchedEmbeddings::Batch(EmbeddingMatrix::from_flat(vec![1.0, 0.0], 1, 2)),
+            usage: None,
+        };
+        let b = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 0.0]),
+            usage: None,
+        };
+
+        assert!(a.cosine_similarity(&b).is_err());
+    }
+
+    #[test]
+    fn test_cosine_similarity_rejects_mismatched_dimensions() {
+        let a = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 0.0]),
+            usage: None,
+        };
+        let b = EmbeddingResponse {
+            output: BatchedEmbeddings::Single(vec![1.0, 0.0, 0.0]),
+            usage: None,
+        };
+
+        assert!(a.cosine_similarity(&b).is_err());
+    }
+
     #[test]
     fn test_embedding_params_creation() {
         let params = EmbeddingParams {
@@ -1,3 +1,4 @@
+use hfendpoints_core::{Validate, ValidationErrors};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use utoipa::ToSchema;
@@ -29,6 +30,27 @@ pub enum MaybeBatched<T> {
     Batched(Vec<T>),
 }
 
+impl<T: Validate> Validate for MaybeBatched<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        match self {
+            Self::Single(item) => {
+                if let Err(e) = item.validate() {
+                    errors.0.extend(e.0);
+                }
+            }
+            Self::Batched(items) => {
+                for item in items {
+                    if let Err(e) = item.validate() {
+                        errors.0.extend(e.0);
+                    }
+                }
+            }
+        }
+        errors.into_result()
+    }
+}
+
 /// The `Usage` structure represents information about token usage during a text generation process.
 ///
 /// # Attributes
@@ -175,6 +197,25 @@ where
     parameters: P,
 }
 
+impl<I, P> Validate for EndpointRequest<I, P>
+where
+    I: ToSchema + Validate,
+    P: ToSchema + Validate,
+{
+    /// Validates `inputs` then `parameters`, collecting failures from both rather than
+    /// stopping at the first one, so a caller can fix every rejected field in a single retry.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        if let Err(e) = self.inputs.validate() {
+            errors.0.extend(e.0);
+        }
+        if let Err(e) = self.parameters.validate() {
+            errors.0.extend(e.0);
+        }
+        errors.into_result()
+    }
+}
+
 /// Generic response representation for endpoints
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[cfg_attr(feature = "python", derive(FromPyObject))]
@@ -201,6 +242,13 @@ where
     pub fn new(inputs: I, parameters: P) -> Self {
         Self { inputs, parameters }
     }
+
+    /// Borrows the tuning parameters alongside the public `inputs` field, for callers (e.g. a
+    /// native handler preprocessing `inputs` in place) that need both without destructuring.
+    #[inline]
+    pub fn parameters(&self) -> &P {
+        &self.parameters
+    }
 }
 
 #[cfg(feature = "python")]
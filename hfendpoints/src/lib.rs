@@ -1,6 +1,7 @@
 #[cfg(feature = "python")]
 mod python {
-    use hfendpoints_binding_python::ImportablePyModuleBuilder;
+    use hfendpoints_audio as audio;
+    use hfendpoints_binding_python::{logging, ImportablePyModuleBuilder};
     use hfendpoints_http as http;
     use hfendpoints_inference as hfinference;
     use hfendpoints_openai as openai;
@@ -11,13 +12,12 @@ mod python {
 
     #[pymodule]
     pub fn _hfendpoints(py: Python, m: Bound<'_, PyModule>) -> PyResult<()> {
-        tracing_subscriber::fmt::init();
-
         let name = m.name()?.extract::<String>()?;
 
         // hfendpoints
         let pymodule_hfendpoints = ImportablePyModuleBuilder::from(m)
             .defaults()?
+            .add_submodule(&logging::bind(py, &format!("{name}.logging"))?)?
             .add_submodule(&http::python::bind(py, &format!("{name}.http"))?)?
             .add_submodule(&hfinference::python::bind(
                 py,
@@ -25,6 +25,7 @@ mod python {
             )?)?
             .add_submodule(&tasks::python::bind(py, &format!("{name}.tasks"))?)?
             .add_submodule(&openai::python::bind(py, &format!("{name}.openai"))?)?
+            .add_submodule(&audio::python::bind(py, &format!("{name}.audio"))?)?
             .finish();
 
         pymodule_hfendpoints.add("__version__", __VERSION__)?;